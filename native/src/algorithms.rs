@@ -1,8 +1,63 @@
-use ndarray::{Array, ArrayView, ArrayViewMut, Axis, Dimension, IntoDimension, Ix2, RemoveAxis, Slice};
+use ndarray::{indices, Array, Array2, ArrayBase, ArrayD, ArrayView, ArrayView1, ArrayViewD, ArrayViewMut, ArrayViewMutD, Axis, Dimension, IntoDimension, Ix1, Ix2, RawData, RemoveAxis, Slice, Zip};
+use rand::Rng;
 use rand_distr::num_traits::Zero;
 
+use crate::error::rynd_normalize_index;
 use crate::rynd_error;
 
+#[derive(Clone, Copy)]
+pub enum PadMode {
+    Constant, Edge, Reflect, Symmetric, Wrap
+}
+
+impl TryFrom<usize> for PadMode {
+    type Error = ();
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PadMode::Constant),
+            1 => Ok(PadMode::Edge),
+            2 => Ok(PadMode::Reflect),
+            3 => Ok(PadMode::Symmetric),
+            4 => Ok(PadMode::Wrap),
+            _ => Err(())
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum TakeMode {
+    Raise, Wrap, Clip
+}
+
+impl TryFrom<usize> for TakeMode {
+    type Error = ();
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TakeMode::Raise),
+            1 => Ok(TakeMode::Wrap),
+            2 => Ok(TakeMode::Clip),
+            _ => Err(())
+        }
+    }
+}
+
+impl TakeMode {
+    // Like NumPy, clipping does not wrap negative indices
+    pub fn normalize(&self, index: i64, len: usize) -> usize {
+        if len == 0 {
+            rynd_error!("Unable to take elements from an empty dimension");
+        }
+
+        match self {
+            TakeMode::Raise => rynd_normalize_index(index, len, false),
+            TakeMode::Wrap => index.rem_euclid(len as i64) as usize,
+            TakeMode::Clip => index.clamp(0, len as i64 - 1) as usize,
+        }
+    }
+}
+
 pub fn sort_view_axis<T, D>(mut view: ArrayViewMut<T, D>, axis: Axis)
 where
     T: PartialOrd + Clone,
@@ -23,6 +78,358 @@ where
     }
 }
 
+// Fisher-Yates shuffle of the subarrays along the first axis
+pub fn shuffle_view<T, D, R>(mut view: ArrayViewMut<T, D>, rng: &mut R)
+where
+    D: RemoveAxis,
+    R: Rng + ?Sized,
+{
+    for i in (1..view.len_of(Axis(0))).rev() {
+        let j = rng.random_range(0..=i);
+
+        if i != j {
+            let (mut left, mut right) = view.view_mut().split_at(Axis(0), i);
+
+            Zip::from(left.index_axis_mut(Axis(0), j))
+                .and(right.index_axis_mut(Axis(0), 0))
+                .for_each(std::mem::swap);
+        }
+    }
+}
+
+// Like NumPy, missing dimensions or repetitions are treated as 1 at the front
+pub fn tile<T: Clone>(view: &ArrayViewD<T>, reps: &[usize]) -> ArrayD<T> {
+    let mut src = view.clone();
+
+    while src.ndim() < reps.len() {
+        src = src.insert_axis(Axis(0));
+    }
+
+    let offset = src.ndim() - reps.len();
+
+    let shape = src.shape().iter().enumerate()
+                   .map(|(i, s)| if i < offset { *s } else { s * reps[i - offset] })
+                   .collect::<Vec<_>>();
+
+    ArrayD::from_shape_fn(shape, |mut idx| {
+        idx.slice_mut().iter_mut().zip(src.shape()).for_each(|(i, s)| *i %= s);
+        src[idx].clone()
+    })
+}
+
+// Source position of every padded position along one axis (None means the constant value)
+fn pad_positions(len: usize, before: usize, after: usize, mode: PadMode) -> Vec<Option<usize>> {
+    if len == 0 && !matches!(mode, PadMode::Constant) && before + after > 0 {
+        rynd_error!("Unable to pad an empty dimension with a non-constant mode");
+    }
+
+    let n = len as i64;
+
+    (0..(len + before + after) as i64).map(|i| {
+        let j = i - before as i64;
+
+        if (0..n).contains(&j) {
+            return Some(j as usize);
+        }
+
+        match mode {
+            PadMode::Constant => None,
+            PadMode::Edge => Some(j.clamp(0, n - 1) as usize),
+            PadMode::Wrap => Some(j.rem_euclid(n) as usize),
+
+            PadMode::Reflect if n == 1 => Some(0),
+            PadMode::Reflect => {
+                let m = j.rem_euclid(2 * (n - 1));
+                Some(if m < n { m } else { 2 * (n - 1) - m } as usize)
+            },
+
+            PadMode::Symmetric => {
+                let m = j.rem_euclid(2 * n);
+                Some(if m < n { m } else { 2 * n - 1 - m } as usize)
+            },
+        }
+    }).collect()
+}
+
+pub fn pad<T: Clone>(view: &ArrayViewD<T>, widths: &[(usize, usize)], mode: PadMode, constant: T) -> ArrayD<T> {
+    let positions = view.shape().iter().zip(widths)
+                        .map(|(l, (b, a))| pad_positions(*l, *b, *a, mode))
+                        .collect::<Vec<_>>();
+
+    let shape = positions.iter().map(Vec::len).collect::<Vec<_>>();
+
+    ArrayD::from_shape_fn(shape, |mut idx| {
+        for (i, p) in idx.slice_mut().iter_mut().zip(&positions) {
+            match p[*i] {
+                Some(j) => *i = j,
+                None => return constant.clone(),
+            }
+        }
+
+        view[idx].clone()
+    })
+}
+
+// Follows NumPy's rules: dimensions are aligned from the end and must be equal or 1
+pub fn broadcast_shape<'a, I: IntoIterator<Item = &'a [usize]>>(shapes: I) -> Option<Vec<usize>> {
+    let mut res: Vec<usize> = vec![];
+
+    for shape in shapes {
+        if shape.len() > res.len() {
+            let mut padded = vec![1; shape.len() - res.len()];
+            padded.extend(res);
+            res = padded;
+        }
+
+        let offset = res.len() - shape.len();
+
+        for (r, d) in res[offset..].iter_mut().zip(shape) {
+            match (*r, *d) {
+                (a, b) if a == b => {},
+                (1, b) => *r = b,
+                (_, 1) => {},
+                _ => return None
+            }
+        }
+    }
+
+    Some(res)
+}
+
+// Broadcasts the index arrays of every indexed axis together and returns the resulting shape and
+// the normalized coordinates of each element in row-major order
+pub fn index_coords(shape: &[usize], indices: &[(usize, ArrayViewD<i64>)]) -> (Vec<usize>, Vec<Vec<usize>>) {
+    let idx_shape = match broadcast_shape(indices.iter().map(|(_, i)| i.shape())) {
+        Some(s) => s,
+        None => rynd_error!(
+            "Unable to broadcast index arrays with shapes {:?}", 
+            indices.iter().map(|(_, i)| i.shape()).collect::<Vec<_>>()
+        ),
+    };
+
+    let broadcast = indices.iter()
+                           .map(|(d, i)| (shape[*d], i.broadcast(idx_shape.clone()).unwrap()))
+                           .collect::<Vec<_>>();
+
+    let mut coords = vec![Vec::with_capacity(indices.len()); idx_shape.iter().product()];
+
+    for (len, idx) in broadcast {
+        for (c, i) in coords.iter_mut().zip(idx.iter()) {
+            c.push(rynd_normalize_index(*i, len, false));
+        }
+    }
+
+    (idx_shape, coords)
+}
+
+// Indexed dimensions are replaced by the index shape, which is placed where the first indexed
+// dimension was if all of them are adjacent and at the front otherwise. Without index arrays this
+// is just a copy
+pub fn advanced_index<T: Clone>(view: &ArrayViewD<T>, indices: &[(usize, ArrayViewD<i64>)]) -> ArrayD<T> {
+    if indices.is_empty() {
+        return view.to_owned();
+    }
+
+    let (idx_shape, coords) = index_coords(view.shape(), indices);
+
+    let indexed = indices.iter().map(|(d, _)| *d).collect::<Vec<_>>();
+    let rest = (0..view.ndim()).filter(|d| !indexed.contains(d)).collect::<Vec<_>>();
+    let adjacent = indexed.windows(2).all(|w| w[1] == w[0] + 1);
+
+    let permuted = view.view().permuted_axes([indexed.clone(), rest.clone()].concat());
+    let mut values = Vec::with_capacity(coords.len() * rest.iter().map(|d| view.shape()[*d]).product::<usize>());
+
+    for c in &coords {
+        let mut sub = permuted.view();
+
+        for i in c {
+            sub = sub.index_axis_move(Axis(0), *i);
+        }
+
+        values.extend(sub.iter().cloned());
+    }
+
+    let res_shape = [idx_shape.clone(), rest.iter().map(|d| view.shape()[*d]).collect()].concat();
+    let res = ArrayD::from_shape_vec(res_shape, values).unwrap();
+
+    let pos = if adjacent { indexed[0] } else { 0 };
+
+    if pos == 0 {
+        return res;
+    }
+
+    let n = idx_shape.len();
+    let perm = [(n..n + pos).collect::<Vec<_>>(), (0..n).collect(), (n + pos..res.ndim()).collect()].concat();
+
+    res.permuted_axes(perm).as_standard_layout().into_owned()
+}
+
+// f64::max and f64::min ignore NaN, while NumPy's maximum and minimum propagate it
+pub fn nan_max(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() { f64::NAN } else { a.max(b) }
+}
+
+pub fn nan_min(a: f64, b: f64) -> f64 {
+    if a.is_nan() || b.is_nan() { f64::NAN } else { a.min(b) }
+}
+
+// Steps to the closest representable float in the direction of y
+pub fn next_after(x: f64, y: f64) -> f64 {
+    if x.is_nan() || y.is_nan() {
+        return f64::NAN;
+    }
+
+    if x == y {
+        return y;
+    }
+
+    if x == 0.0 {
+        return f64::from_bits(1).copysign(y);
+    }
+
+    let bits = x.to_bits();
+
+    // Magnitudes grow with the bit pattern, so moving away from zero increments it
+    if (y > x) == (x > 0.0) {
+        f64::from_bits(bits + 1)
+    } else {
+        f64::from_bits(bits - 1)
+    }
+}
+
+// Equal arguments are handled separately so infinities do not produce NaN
+pub fn log_add_exp(a: f64, b: f64) -> f64 {
+    if a == b {
+        return a + std::f64::consts::LN_2;
+    }
+
+    a.max(b) + (-(a - b).abs()).exp().ln_1p()
+}
+
+pub fn log_add_exp2(a: f64, b: f64) -> f64 {
+    if a == b {
+        return a + 1.0;
+    }
+
+    a.max(b) + (-(a - b).abs()).exp2().ln_1p() / std::f64::consts::LN_2
+}
+
+#[derive(Clone, Copy)]
+pub enum ScatterOp {
+    Assign, Add, Max, Min
+}
+
+// Bool arrays accumulate with logical operations and floats propagate NaN like NumPy's maximum and minimum
+pub trait ScatterValue: Clone {
+    fn add(&mut self, other: &Self);
+    fn max(&mut self, other: &Self);
+    fn min(&mut self, other: &Self);
+}
+
+impl ScatterValue for i64 {
+    fn add(&mut self, other: &Self) { *self += *other; }
+    fn max(&mut self, other: &Self) { *self = (*self).max(*other); }
+    fn min(&mut self, other: &Self) { *self = (*self).min(*other); }
+}
+
+impl ScatterValue for f64 {
+    fn add(&mut self, other: &Self) { *self += *other; }
+    fn max(&mut self, other: &Self) { *self = nan_max(*self, *other); }
+    fn min(&mut self, other: &Self) { *self = nan_min(*self, *other); }
+}
+
+impl ScatterValue for bool {
+    fn add(&mut self, other: &Self) { *self |= *other; }
+    fn max(&mut self, other: &Self) { *self |= *other; }
+    fn min(&mut self, other: &Self) { *self &= *other; }
+}
+
+// Indices select the leading dimensions and values are broadcast to the index shape followed by the
+// remaining dimensions. Elements are updated in order, so repeated indices accumulate
+pub fn scatter<T: ScatterValue>(mut view: ArrayViewMutD<T>, idx: &[ArrayViewD<i64>], values: &ArrayViewD<T>, op: ScatterOp) {
+    let spec = idx.iter().cloned().enumerate().collect::<Vec<_>>();
+    let (idx_shape, coords) = index_coords(view.shape(), &spec);
+    let target_shape = [idx_shape.clone(), view.shape()[idx.len()..].to_vec()].concat();
+
+    let values = match values.broadcast(target_shape.clone()) {
+        Some(v) => v,
+        None => rynd_error!("Unable to broadcast values with shape {:?} to shape {:?}", values.shape(), target_shape),
+    };
+
+    for (c, pos) in coords.iter().zip(indices(idx_shape)) {
+        let mut target = view.view_mut();
+        let mut source = values.view();
+
+        for i in c {
+            target = target.index_axis_move(Axis(0), *i);
+        }
+
+        for i in pos.slice() {
+            source = source.index_axis_move(Axis(0), *i);
+        }
+
+        Zip::from(target).and(source).for_each(|a, b| match op {
+            ScatterOp::Assign => *a = b.clone(),
+            ScatterOp::Add => a.add(b),
+            ScatterOp::Max => a.max(b),
+            ScatterOp::Min => a.min(b),
+        });
+    }
+}
+
+// The selected positions replace the axis with the shape of the indices
+pub fn take<T: Clone>(view: &ArrayViewD<T>, axis: usize, indices: &[usize], idx_shape: &[usize]) -> ArrayD<T> {
+    let shape = [&view.shape()[..axis], idx_shape, &view.shape()[axis + 1..]].concat();
+
+    view.select(Axis(axis), indices).as_standard_layout().into_owned().into_shape_with_order(shape).unwrap()
+}
+
+// Every axis other than the indexed one is indexed by its positions, so they broadcast against the indices
+fn along_axis_indices(shape: &[usize], indices: &ArrayViewD<i64>, axis: usize) -> Vec<ArrayD<i64>> {
+    (0..shape.len()).map(|d| {
+        if d == axis {
+            return indices.to_owned();
+        }
+
+        let mut idx_shape = vec![1; shape.len()];
+        idx_shape[d] = shape[d];
+
+        Array::from_iter(0..shape[d] as i64).into_shape_with_order(idx_shape).unwrap()
+    }).collect()
+}
+
+pub fn take_along_axis<T: Clone>(view: &ArrayViewD<T>, indices: &ArrayViewD<i64>, axis: usize) -> ArrayD<T> {
+    let idx = along_axis_indices(view.shape(), indices, axis);
+
+    advanced_index(view, &idx.iter().map(|i| i.view()).enumerate().collect::<Vec<_>>())
+}
+
+pub fn put_along_axis<T: ScatterValue>(view: ArrayViewMutD<T>, indices: &ArrayViewD<i64>, values: &ArrayViewD<T>, axis: usize) {
+    let idx = along_axis_indices(view.shape(), indices, axis);
+
+    scatter(view, &idx.iter().map(|i| i.view()).collect::<Vec<_>>(), values, ScatterOp::Assign);
+}
+
+pub fn zip_broadcast<A, B, C, F: Fn(&A, &B) -> C>(a: &ArrayViewD<A>, b: &ArrayViewD<B>, f: F) -> ArrayD<C> {
+    let shape = match broadcast_shape([a.shape(), b.shape()]) {
+        Some(s) => s,
+        None => rynd_error!("Unable to broadcast arrays with shapes {:?} and {:?}", a.shape(), b.shape()),
+    };
+
+    Zip::from(a.broadcast(shape.clone()).unwrap()).and(b.broadcast(shape).unwrap()).map_collect(f)
+}
+
+// Arguments are expected to be broadcast to the same shape already
+pub fn where_cond<T: Clone>(cond: &ArrayViewD<bool>, x: &ArrayViewD<T>, y: &ArrayViewD<T>) -> ArrayD<T> {
+    Zip::from(cond).and(x).and(y).map_collect(|c, a, b| if *c { a.clone() } else { b.clone() })
+}
+
+pub fn choose<T: Clone>(indices: &ArrayViewD<i64>, choices: &[ArrayViewD<T>], mode: TakeMode) -> ArrayD<T> {
+    ArrayD::from_shape_fn(indices.raw_dim(), |pos| {
+        choices[mode.normalize(indices[&pos], choices.len())][&pos].clone()
+    })
+}
+
 pub fn argsort_axis<T, D>(view: &ArrayView<T, D>, axis: Axis) -> Array<i64, D>
 where
     T: PartialOrd,
@@ -63,11 +470,13 @@ where
     }
 }
 
-pub fn reverse_axis<'a, T, D>(view: &'a mut ArrayViewMut<T, D>, axis: Axis) -> ArrayViewMut<'a, T, D>
+pub fn reverse_axis<S, D>(mut view: ArrayBase<S, D>, axis: Axis) -> ArrayBase<S, D>
 where
+    S: RawData,
     D: Dimension,
 {
-    view.slice_axis_mut(axis, Slice::from(0..).step_by(-1))
+    view.slice_axis_inplace(axis, Slice::from(0..).step_by(-1));
+    view
 }
 
 pub fn diff_axis<T, D>(view: &ArrayView<T, D>, axis: Axis) -> Array<T, D>
@@ -171,4 +580,310 @@ where
     let num_nonzero = indices.len() / ndim;
     
     Array::from_shape_vec((num_nonzero, ndim), indices).unwrap()
-}
\ No newline at end of file
+}
+
+pub fn eye<T>(n: usize, m: usize, k: i64, one: T) -> Array2<T>
+where
+    T: Clone + Default,
+{
+    Array2::from_shape_fn((n, m), |(i, j)| {
+        if j as i64 - i as i64 == k {
+            one.clone()
+        } else {
+            T::default()
+        }
+    })
+}
+
+pub fn diag_from<T>(view: &ArrayView1<T>, k: i64) -> Array2<T>
+where
+    T: Clone + Default,
+{
+    let n = view.len() + k.unsigned_abs() as usize;
+    let (row_offset, col_offset) = if k >= 0 { (0, k as usize) } else { ((-k) as usize, 0) };
+    let mut result = Array2::<T>::default((n, n));
+
+    for (i, v) in view.iter().enumerate() {
+        result[[i + row_offset, i + col_offset]] = v.clone();
+    }
+
+    result
+}
+
+pub fn diagonal_axis<S>(mut view: ArrayBase<S, Ix2>, k: i64) -> ArrayBase<S, Ix1>
+where
+    S: RawData,
+{
+    if k >= 0 {
+        let offset = (k as usize).min(view.ncols());
+        view.slice_axis_inplace(Axis(1), Slice::from(offset..));
+
+    } else {
+        let offset = ((-k) as usize).min(view.nrows());
+        view.slice_axis_inplace(Axis(0), Slice::from(offset..));
+    }
+
+    view.into_diag()
+}
+
+pub fn triangle<T, D>(view: &ArrayView<T, D>, k: i64, upper: bool) -> Array<T, D>
+where
+    T: Clone + Default,
+    D: Dimension,
+{
+    let mut result = view.to_owned();
+    let ndim = result.ndim();
+
+    for (idx, v) in result.indexed_iter_mut() {
+        let idx = idx.into_dimension();
+        let offset = idx[ndim - 1] as i64 - idx[ndim - 2] as i64;
+
+        if (upper && offset < k) || (!upper && offset > k) {
+            *v = T::default();
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array2};
+    use rand::SeedableRng;
+
+    use super::*;
+
+    #[test]
+    fn eye_offsets() {
+        assert_eq!(eye(2, 3, 0, 1i64), array![[1, 0, 0], [0, 1, 0]]);
+        assert_eq!(eye(3, 3, 1, 1i64), array![[0, 1, 0], [0, 0, 1], [0, 0, 0]]);
+        assert_eq!(eye(3, 2, -2, true), array![[false, false], [false, false], [true, false]]);
+        assert_eq!(eye(2, 2, 5, 1.0), Array2::<f64>::zeros((2, 2)));
+    }
+
+    #[test]
+    fn diagonals() {
+        let v = array![1, 2];
+        assert_eq!(diag_from(&v.view(), 0), array![[1, 0], [0, 2]]);
+        assert_eq!(diag_from(&v.view(), 1), array![[0, 1, 0], [0, 0, 2], [0, 0, 0]]);
+        assert_eq!(diag_from(&v.view(), -1), array![[0, 0, 0], [1, 0, 0], [0, 2, 0]]);
+
+        let a = array![[1, 2, 3], [4, 5, 6]];
+        assert_eq!(diagonal_axis(a.view(), 0), array![1, 5]);
+        assert_eq!(diagonal_axis(a.view(), 1), array![2, 6]);
+        assert_eq!(diagonal_axis(a.view(), -1), array![4]);
+        assert_eq!(diagonal_axis(a.view(), 3).len(), 0);
+        assert_eq!(diagonal_axis(a.view(), -3).len(), 0);
+    }
+
+    #[test]
+    fn triangles() {
+        let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        assert_eq!(triangle(&a.view(), 0, true), array![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+        assert_eq!(triangle(&a.view(), 1, true), array![[0, 2, 3], [0, 0, 6], [0, 0, 0]]);
+        assert_eq!(triangle(&a.view(), 0, false), array![[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+        assert_eq!(triangle(&a.view(), -1, false), array![[0, 0, 0], [4, 0, 0], [7, 8, 0]]);
+
+        // Stacks of matrices use the last two axes
+        let stacked = ndarray::stack![Axis(0), a, a];
+        assert_eq!(triangle(&stacked.view(), 0, true).index_axis(Axis(0), 1), triangle(&a.view(), 0, true));
+    }
+
+    #[test]
+    fn shuffle_moves_whole_rows() {
+        let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(3);
+        let mut a = Array2::from_shape_fn((20, 3), |(i, j)| (i * 10 + j) as i64);
+        shuffle_view(a.view_mut(), &mut rng);
+
+        assert!(a.rows().into_iter().all(|r| r[1] == r[0] + 1 && r[2] == r[0] + 2));
+
+        let mut firsts = a.column(0).to_vec();
+        firsts.sort();
+        assert_eq!(firsts, (0..20).map(|i| i * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn tiling() {
+        let a = array![[1, 2], [3, 4]].into_dyn();
+        assert_eq!(tile(&a.view(), &[1, 2]), array![[1, 2, 1, 2], [3, 4, 3, 4]].into_dyn());
+        assert_eq!(tile(&a.view(), &[2]), array![[1, 2, 1, 2], [3, 4, 3, 4]].into_dyn());
+        assert_eq!(tile(&a.view(), &[2, 1, 1]).shape(), [2, 2, 2]);
+        assert_eq!(tile(&a.view(), &[0, 1]).shape(), [0, 2]);
+    }
+
+    #[test]
+    fn padding_modes() {
+        let a = array![1, 2, 3].into_dyn();
+        let padded = |mode| pad(&a.view(), &[(2, 2)], mode, 0).into_raw_vec_and_offset().0;
+
+        assert_eq!(padded(PadMode::Constant), [0, 0, 1, 2, 3, 0, 0]);
+        assert_eq!(padded(PadMode::Edge), [1, 1, 1, 2, 3, 3, 3]);
+        assert_eq!(padded(PadMode::Reflect), [3, 2, 1, 2, 3, 2, 1]);
+        assert_eq!(padded(PadMode::Symmetric), [2, 1, 1, 2, 3, 3, 2]);
+        assert_eq!(padded(PadMode::Wrap), [2, 3, 1, 2, 3, 1, 2]);
+
+        // Widths larger than the dimension keep repeating the pattern
+        assert_eq!(pad(&a.view(), &[(4, 0)], PadMode::Wrap, 0), array![3, 1, 2, 3, 1, 2, 3].into_dyn());
+        assert_eq!(pad(&array![7].into_dyn().view(), &[(1, 2)], PadMode::Reflect, 0), array![7, 7, 7, 7].into_dyn());
+
+        let m = array![[1, 2], [3, 4]].into_dyn();
+        assert_eq!(pad(&m.view(), &[(1, 0), (0, 1)], PadMode::Constant, 9), array![[9, 9, 9], [1, 2, 9], [3, 4, 9]].into_dyn());
+        assert_eq!(pad(&m.view(), &[(0, 1), (1, 0)], PadMode::Edge, 0), array![[1, 1, 2], [3, 3, 4], [3, 3, 4]].into_dyn());
+    }
+
+    #[test]
+    fn advanced_indexing() {
+        let a = ArrayD::from_shape_fn(vec![3, 4], |i| (i[0] * 4 + i[1]) as i64);
+        let b = ArrayD::from_shape_fn(vec![2, 3, 4], |i| (i[0] * 12 + i[1] * 4 + i[2]) as i64);
+
+        let rows = array![2, -3].into_dyn();
+        assert_eq!(advanced_index(&a.view(), &[(0, rows.view())]), array![[8, 9, 10, 11], [0, 1, 2, 3]].into_dyn());
+
+        let (r, c) = (array![0, 2].into_dyn(), array![1, 3].into_dyn());
+        assert_eq!(advanced_index(&a.view(), &[(0, r.view()), (1, c.view())]), array![1, 11].into_dyn());
+
+        // Index arrays are broadcast together
+        let (r, c) = (array![[0], [2]].into_dyn(), array![1, 2].into_dyn());
+        assert_eq!(advanced_index(&a.view(), &[(0, r.view()), (1, c.view())]), array![[1, 2], [9, 10]].into_dyn());
+
+        // Adjacent indexed dimensions keep their position, separated ones go to the front
+        let cols = array![[0], [3]].into_dyn();
+        assert_eq!(advanced_index(&a.view(), &[(1, cols.view())]), array![[[0], [3]], [[4], [7]], [[8], [11]]].into_dyn());
+
+        let (first, last) = (array![1, 0].into_dyn(), array![3, 0].into_dyn());
+        assert_eq!(advanced_index(&b.view(), &[(0, first.view()), (2, last.view())]), array![[15, 19, 23], [0, 4, 8]].into_dyn());
+
+        assert_eq!(advanced_index(&b.view(), &[]), b);
+    }
+
+    #[test]
+    fn scatter_ops() {
+        let scattered = |op, idx: &[ArrayD<i64>], values: ArrayD<i64>| {
+            let mut a = array![[1, 2], [3, 4], [5, 6]].into_dyn();
+            scatter(a.view_mut(), &idx.iter().map(|i| i.view()).collect::<Vec<_>>(), &values.view(), op);
+            a
+        };
+
+        let rows = [array![0, 2, 0].into_dyn()];
+
+        // Repeated indices accumulate, and the last assignment wins
+        assert_eq!(scattered(ScatterOp::Add, &rows, array![10].into_dyn()), array![[21, 22], [3, 4], [15, 16]].into_dyn());
+        assert_eq!(scattered(ScatterOp::Assign, &rows, array![[7, 7], [8, 8], [9, 9]].into_dyn()), array![[9, 9], [3, 4], [8, 8]].into_dyn());
+        assert_eq!(scattered(ScatterOp::Max, &rows, array![[0, 3]].into_dyn()), array![[1, 3], [3, 4], [5, 6]].into_dyn());
+        assert_eq!(scattered(ScatterOp::Min, &rows, array![[0, 3]].into_dyn()), array![[0, 2], [3, 4], [0, 3]].into_dyn());
+
+        let cells = [array![-1, 1].into_dyn(), array![0, 1].into_dyn()];
+        assert_eq!(scattered(ScatterOp::Add, &cells, array![100, 200].into_dyn()), array![[1, 2], [3, 204], [105, 6]].into_dyn());
+
+        let mut f = array![1.0, 2.0].into_dyn();
+        scatter(f.view_mut(), &[array![0, 1].into_dyn().view()], &array![f64::NAN, 0.5].into_dyn().view(), ScatterOp::Max);
+        assert!(f[0].is_nan() && f[1] == 2.0);
+
+        let mut b = array![false, true].into_dyn();
+        scatter(b.view_mut(), &[array![0, 1].into_dyn().view()], &array![true].into_dyn().view(), ScatterOp::Min);
+        assert_eq!(b, array![false, true].into_dyn());
+    }
+
+    #[test]
+    fn take_modes() {
+        assert_eq!(TakeMode::Raise.normalize(-1, 3), 2);
+        assert_eq!(TakeMode::Wrap.normalize(5, 3), 2);
+        assert_eq!(TakeMode::Wrap.normalize(-4, 3), 2);
+        assert_eq!(TakeMode::Clip.normalize(-1, 3), 0);
+        assert_eq!(TakeMode::Clip.normalize(7, 3), 2);
+
+        let a = array![[1, 2, 3], [4, 5, 6]].into_dyn();
+        assert_eq!(take(&a.view(), 1, &[2, 0], &[2]), array![[3, 1], [6, 4]].into_dyn());
+        assert_eq!(take(&a.view(), 0, &[1], &[]), array![4, 5, 6].into_dyn());
+        assert_eq!(take(&a.view(), 1, &[0, 1], &[2, 1]), array![[[1], [2]], [[4], [5]]].into_dyn());
+    }
+
+    #[test]
+    fn along_axis() {
+        let a = array![[10, 30, 20], [60, 40, 50]].into_dyn();
+
+        let order = array![[0, 2, 1], [1, 2, 0]].into_dyn();
+        assert_eq!(take_along_axis(&a.view(), &order.view(), 1), array![[10, 20, 30], [40, 50, 60]].into_dyn());
+
+        let rows = array![[1, 0, 1]].into_dyn();
+        assert_eq!(take_along_axis(&a.view(), &rows.view(), 0), array![[60, 30, 50]].into_dyn());
+
+        let mut b = a.clone();
+        put_along_axis(b.view_mut(), &array![[1], [-1]].into_dyn().view(), &array![0].into_dyn().view(), 1);
+        assert_eq!(b, array![[10, 0, 20], [60, 40, 0]].into_dyn());
+    }
+
+    #[test]
+    fn where_and_choose() {
+        let cond = array![[true, false], [false, true]].into_dyn();
+        let x = array![[1, 2], [3, 4]].into_dyn();
+        let y = array![[5, 6], [7, 8]].into_dyn();
+        assert_eq!(where_cond(&cond.view(), &x.view(), &y.view()), array![[1, 6], [7, 4]].into_dyn());
+
+        let choices = [array![0, 1, 2].into_dyn(), array![10, 11, 12].into_dyn()];
+        let views = choices.iter().map(|c| c.view()).collect::<Vec<_>>();
+
+        let idx = array![1, 0, 1].into_dyn();
+        assert_eq!(choose(&idx.view(), &views, TakeMode::Raise), array![10, 1, 12].into_dyn());
+
+        // Out of range indices are only accepted when wrapping or clipping
+        let idx = array![-1, 2, 3].into_dyn();
+        assert_eq!(choose(&idx.view(), &views, TakeMode::Wrap), array![10, 1, 12].into_dyn());
+        assert_eq!(choose(&idx.view(), &views, TakeMode::Clip), array![0, 11, 12].into_dyn());
+    }
+
+    #[test]
+    fn nan_propagating_extremes() {
+        assert_eq!(nan_max(1.0, 2.0), 2.0);
+        assert_eq!(nan_min(1.0, 2.0), 1.0);
+        assert!(nan_max(f64::NAN, 2.0).is_nan() && nan_max(1.0, f64::NAN).is_nan());
+        assert!(nan_min(f64::NAN, 2.0).is_nan() && nan_min(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn broadcast_zips() {
+        let a = array![[1, 2, 3]].into_dyn();
+        let b = array![[10], [20]].into_dyn();
+        assert_eq!(zip_broadcast(&a.view(), &b.view(), |x, y| x + y), array![[11, 12, 13], [21, 22, 23]].into_dyn());
+
+        let c = ndarray::arr0(0.5).into_dyn();
+        assert_eq!(zip_broadcast(&a.view(), &c.view(), |x, y| *x as f64 * y), array![[0.5, 1.0, 1.5]].into_dyn());
+    }
+
+    #[test]
+    fn next_representable_floats() {
+        assert_eq!(next_after(1.0, 2.0), 1.0 + f64::EPSILON);
+        assert_eq!(next_after(1.0, 0.0), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(next_after(-1.0, -2.0), -1.0 - f64::EPSILON);
+        assert_eq!(next_after(-1.0, 0.0), -1.0 + f64::EPSILON / 2.0);
+
+        // Zero steps to the smallest subnormal with the sign of the target
+        assert_eq!(next_after(0.0, 1.0), f64::from_bits(1));
+        assert_eq!(next_after(0.0, -1.0), -f64::from_bits(1));
+        assert_eq!(next_after(f64::from_bits(1), -1.0), 0.0);
+
+        assert_eq!(next_after(2.0, 2.0), 2.0);
+        assert_eq!(next_after(f64::MAX, f64::INFINITY), f64::INFINITY);
+        assert_eq!(next_after(f64::INFINITY, 0.0), f64::MAX);
+        assert!(next_after(f64::NAN, 1.0).is_nan() && next_after(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn log_add_exps() {
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-12 * b.abs().max(1.0);
+
+        assert!(close(log_add_exp(1.0, 2.0), (1.0f64.exp() + 2.0f64.exp()).ln()));
+        assert!(close(log_add_exp(0.0, 0.0), std::f64::consts::LN_2));
+        assert!(close(log_add_exp2(1.0, 3.0), 10.0f64.log2()));
+        assert!(close(log_add_exp2(5.0, 5.0), 6.0));
+
+        // Large arguments do not overflow
+        assert!(close(log_add_exp(1000.0, 1000.0), 1000.0 + std::f64::consts::LN_2));
+        assert!(close(log_add_exp2(2000.0, 1999.0), 2000.0 + 1.5f64.log2()));
+
+        assert_eq!(log_add_exp(f64::NEG_INFINITY, f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert_eq!(log_add_exp(f64::INFINITY, f64::INFINITY), f64::INFINITY);
+        assert_eq!(log_add_exp2(f64::NEG_INFINITY, 3.0), 3.0);
+        assert!(log_add_exp(f64::NAN, 1.0).is_nan() && log_add_exp2(1.0, f64::NAN).is_nan());
+    }
+}
@@ -1,8 +1,9 @@
 use std::{fmt::Display, os::raw::c_void};
 
 use ndarray::Slice;
+use rand::Rng;
 
-use crate::{owned::{NDArrayOwned, NDArrayType}, rynd_error, view::NDArrayView};
+use crate::{algorithms::{broadcast_shape, PadMode, ScatterOp, TakeMode}, linalg::MatrixNorm, owned::{NDArrayOwned, NDArrayType}, rynd_error, view::NDArrayView};
 
 #[derive(Clone)]
 pub enum NDArray {
@@ -95,6 +96,14 @@ impl NDArray {
         }
     }
 
+    // Copies of views own their data, so they neither alias the original array nor inherit its read-only flag
+    pub fn copy(&self) -> NDArray {
+        match self {
+            NDArray::Owned(_) => self.clone(),
+            NDArray::View(v) => NDArray::from(v.owned()),
+        }
+    }
+
     pub fn view(&mut self) -> NDArrayView {
         match self {
             NDArray::Owned(a) => a.view(),
@@ -102,6 +111,13 @@ impl NDArray {
         }
     }
 
+    pub fn pad(&mut self, widths: &[(usize, usize)], mode: PadMode, constant: f64) -> Self {
+        match self {
+            NDArray::Owned(a) => a.pad(widths, mode, constant).into(),
+            NDArray::View(v) => v.owned().pad(widths, mode, constant).into(),
+        }
+    }
+
     pub fn cast(&mut self, tp: NDArrayType) -> Self {
         match self {
             NDArray::Owned(a) => a.cast(tp).into(),
@@ -109,6 +125,13 @@ impl NDArray {
         }
     }
 
+    pub fn dtype(&self) -> NDArrayType {
+        match self {
+            NDArray::Owned(a) => a.dtype(),
+            NDArray::View(v) => v.dtype(),
+        }
+    }
+
     pub fn len(&self) -> usize {
         match self {
             NDArray::Owned(a) => a.len(),
@@ -151,6 +174,49 @@ impl NDArray {
         obj.index(&idx_view).into()
     }
 
+    pub fn advanced_index(&mut self, indices: &mut [(usize, &mut NDArray)]) -> Self {
+        let views = indices.iter_mut().map(|(d, i)| (*d, i.view())).collect::<Vec<_>>();
+
+        self.view().advanced_index(&views).into()
+    }
+
+    // Values are converted to the type of the array before being written. Both values and indices are
+    // copied first, since they may share memory with the array (as in add_at(a, idx, a))
+    pub fn scatter(&mut self, indices: &mut [&mut NDArray], values: &mut NDArray, op: ScatterOp) {
+        let mut idx = indices.iter_mut().map(|i| i.view().owned()).collect::<Vec<_>>();
+        let idx_views = idx.iter_mut().map(|i| i.view()).collect::<Vec<_>>();
+        let mut values = values.cast(self.dtype());
+
+        self.view().scatter(&idx_views, &values.view(), op);
+    }
+
+    fn as_dtype(&mut self, tp: NDArrayType) -> NDArray {
+        match self.dtype() == tp {
+            true => NDArray::from(self.view()),
+            false => self.cast(tp),
+        }
+    }
+
+    pub fn take(&mut self, indices: &[usize], idx_shape: &[usize], axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+
+        source.view().take(axis.unwrap_or(0), indices, idx_shape).into()
+    }
+
+    pub fn take_along_axis(&mut self, indices: &mut NDArray, axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+
+        source.view().take_along_axis(&indices.view(), axis.unwrap_or(0)).into()
+    }
+
+    // Values and indices are copied before writing, like in scatter
+    pub fn put_along_axis(&mut self, indices: &mut NDArray, values: &mut NDArray, axis: usize) {
+        let mut indices = indices.view().owned();
+        let mut values = values.cast(self.dtype());
+
+        self.view().put_along_axis(&indices.view(), &values.view(), axis);
+    }
+
     fn compatible_shapes(a: &[usize], b: &[usize]) -> bool {
         a.iter().product::<usize>() == b.iter().product::<usize>()
     }
@@ -173,6 +239,126 @@ impl NDArray {
         }
     }
 
+    // Functions without an axis work over the flattened array, as in NumPy
+    fn axis_source(&mut self, axis: Option<usize>) -> NDArray {
+        match axis {
+            Some(_) => NDArray::from(self.view()),
+            None => NDArray::from(self.view().flatten()),
+        }
+    }
+
+    pub fn delete(&mut self, indices: &[usize], axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+        let axis = axis.unwrap_or(0);
+        let mut keep = vec![true; source.shape()[axis]];
+
+        indices.iter().for_each(|i| keep[*i] = false);
+
+        let kept = (0..keep.len()).filter(|i| keep[*i]).collect::<Vec<_>>();
+
+        source.select(axis, &kept)
+    }
+
+    // Values are broadcast to one slice per index and converted to the type of the array
+    pub fn insert(&mut self, indices: &[usize], values: &mut NDArray, axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+        let axis = axis.unwrap_or(0);
+        let len = source.shape()[axis];
+
+        let mut block_shape = source.shape().to_vec();
+        block_shape[axis] = indices.len();
+
+        let mut block = values.broadcast_to(&block_shape);
+        let mut combined = NDArray::concatenate(&mut [&mut source, &mut block], axis);
+
+        let mut order = (0..indices.len()).collect::<Vec<_>>();
+        order.sort_by_key(|k| indices[*k]);
+
+        let mut inserted = order.into_iter().peekable();
+        let mut positions = Vec::with_capacity(len + indices.len());
+
+        for i in 0..=len {
+            while let Some(k) = inserted.next_if(|k| indices[*k] == i) {
+                positions.push(len + k);
+            }
+
+            if i < len {
+                positions.push(i);
+            }
+        }
+
+        let mut res = combined.select(axis, &positions);
+
+        match res.dtype() == self.dtype() {
+            true => res,
+            false => res.cast(self.dtype()),
+        }
+    }
+
+    // A single index inserts every value along the axis, so values get at least the dimensions of the
+    // array and their first axis is moved to the insertion axis (as NumPy does)
+    pub fn insert_at(&mut self, index: usize, values: &mut NDArray, axis: Option<usize>) -> NDArray {
+        let ndim = if axis.is_some() { self.shape().len() } else { 1 };
+        let mut values = NDArray::from(values.view());
+
+        while values.shape().len() < ndim {
+            values = values.expand_dims(0);
+        }
+
+        let mut values = values.move_axis(0, axis.unwrap_or(0));
+        let count = values.shape()[axis.unwrap_or(0)];
+
+        self.insert(&vec![index; count], &mut values, axis)
+    }
+
+    pub fn append(&mut self, values: &mut NDArray, axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+        let mut values = values.axis_source(axis);
+
+        NDArray::concatenate(&mut [&mut source, &mut values], axis.unwrap_or(0))
+    }
+
+    pub fn repeat(&mut self, counts: &[usize], axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+
+        let axis = axis.unwrap_or(0);
+        let len = source.shape()[axis];
+
+        if counts.len() != 1 && counts.len() != len {
+            rynd_error!("Unable to repeat {} elements with {} repetition counts", len, counts.len());
+        }
+
+        let indices = (0..len).flat_map(|i| std::iter::repeat_n(i, counts[i.min(counts.len() - 1)]))
+                              .collect::<Vec<_>>();
+
+        source.select(axis, &indices)
+    }
+
+    pub fn tile(&mut self, reps: &[usize]) -> NDArray {
+        NDArray::from(self.view().tile(reps))
+    }
+
+    pub fn broadcast_to(&mut self, shape: &[usize]) -> NDArray {
+        match self.view().broadcast_to(shape) {
+            Some(v) => v.into(),
+            None => rynd_error!("Unable to broadcast array of shape {:?} to shape {:?}", self.shape(), shape),
+        }
+    }
+
+    pub fn slice_axis(&mut self, axis: usize, slice: Slice) -> Self {
+        match self {
+            NDArray::Owned(a) => a.view().slice_axis(axis, slice).into(),
+            NDArray::View(v) => v.slice_axis(axis, slice).into(),
+        }
+    }
+
+    pub fn diagonal(&mut self, k: i64) -> NDArray {
+        match self {
+            NDArray::Owned(a) => a.view().diagonal(k).into(),
+            NDArray::View(v) => v.diagonal(k).into(),
+        }
+    }
+
     pub fn permute(&mut self, permutation: &[usize]) -> NDArray {
         match self {
             NDArray::Owned(a) => a.view().permute(permutation).into(),
@@ -180,6 +366,34 @@ impl NDArray {
         }
     }
 
+    pub fn squeeze(&mut self, axes: &[usize]) -> NDArray {
+        match self {
+            NDArray::Owned(a) => a.view().remove_axes(axes).into(),
+            NDArray::View(a) => a.remove_axes(axes).into(),
+        }
+    }
+
+    pub fn expand_dims(&mut self, axis: usize) -> NDArray {
+        match self {
+            NDArray::Owned(a) => a.view().expand_dims(axis).into(),
+            NDArray::View(a) => a.expand_dims(axis).into(),
+        }
+    }
+
+    pub fn swap_axes(&mut self, a: usize, b: usize) -> NDArray {
+        match self {
+            NDArray::Owned(v) => v.view().swap_axes(a, b).into(),
+            NDArray::View(v) => v.swap_axes(a, b).into(),
+        }
+    }
+
+    pub fn move_axis(&mut self, source: usize, destination: usize) -> NDArray {
+        let mut perm = (0..self.shape().len()).filter(|i| *i != source).collect::<Vec<_>>();
+        perm.insert(destination, source);
+
+        self.permute(&perm)
+    }
+
     pub fn matmul(&mut self, other: &mut NDArray) -> NDArray {
         match (self, other) {
             (NDArray::Owned(a), NDArray::Owned(b)) => a.view().matmul(&b.view()).into(),
@@ -189,6 +403,74 @@ impl NDArray {
         }
     }
 
+    pub fn solve(&mut self, other: &mut NDArray) -> NDArray {
+        NDArray::from(self.view().solve(&other.view()))
+    }
+
+    pub fn inv(&mut self) -> NDArray {
+        NDArray::from(self.view().inv())
+    }
+
+    pub fn det(&mut self) -> f64 {
+        self.view().det()
+    }
+
+    pub fn slogdet(&mut self) -> NDArray {
+        NDArray::from(self.view().slogdet())
+    }
+
+    pub fn matrix_rank(&mut self, tol: Option<f64>) -> i64 {
+        self.view().matrix_rank(tol)
+    }
+
+    pub fn matrix_power(&mut self, n: i64) -> NDArray {
+        NDArray::from(self.view().matrix_power(n))
+    }
+
+    pub fn pinv(&mut self, rcond: Option<f64>) -> NDArray {
+        NDArray::from(self.view().pinv(rcond))
+    }
+
+    pub fn lstsq(&mut self, other: &mut NDArray, rcond: Option<f64>) -> Vec<NDArray> {
+        self.view().lstsq(&other.view(), rcond).into_iter().map(NDArray::from).collect()
+    }
+
+    pub fn norm(&mut self, p: f64) -> f64 {
+        self.view().norm(p)
+    }
+
+    pub fn axis_norm(&mut self, p: f64, axis: usize) -> NDArray {
+        NDArray::from(self.view().axis_norm(p, axis))
+    }
+
+    pub fn matrix_norm(&mut self, norm: MatrixNorm) -> f64 {
+        self.view().matrix_norm(norm)
+    }
+
+    pub fn lu(&mut self) -> Vec<NDArray> {
+        self.view().lu().into_iter().map(NDArray::from).collect()
+    }
+
+    pub fn qr(&mut self, complete: bool) -> Vec<NDArray> {
+        self.view().qr(complete).into_iter().map(NDArray::from).collect()
+    }
+
+    pub fn cholesky(&mut self) -> NDArray {
+        NDArray::from(self.view().cholesky())
+    }
+
+    pub fn svd(&mut self, full: bool) -> Vec<NDArray> {
+        self.view().svd(full).into_iter().map(NDArray::from).collect()
+    }
+
+    pub fn eigh(&mut self) -> Vec<NDArray> {
+        self.view().eigh().into_iter().map(NDArray::from).collect()
+    }
+
+    pub fn eig(&mut self) -> Vec<NDArray> {
+        self.view().eig().into_iter().map(NDArray::from).collect()
+    }
+
     pub fn assign(&mut self, other: &mut NDArray) {
         match self {
             NDArray::Owned(a) => a.view().assign(&other.view()),
@@ -220,6 +502,17 @@ impl NDArray {
     view_binop!(gt);
     view_binop!(leq);
     view_binop!(geq);
+    view_binop!(maximum);
+    view_binop!(minimum);
+    view_binop!(fmax);
+    view_binop!(fmin);
+    view_binop!(atan2);
+    view_binop!(hypot);
+    view_binop!(copysign);
+    view_binop!(nextafter);
+    view_binop!(logaddexp);
+    view_binop!(logaddexp2);
+    view_binop!(float_power);
 
     pub fn assign_scalar_i64(&mut self, other: i64) {
         match self {
@@ -285,6 +578,39 @@ impl NDArray {
     view_binop_scalar!(geq_scalar_i64, i64);
     view_binop_scalar!(geq_scalar_f64, f64);
 
+    view_binop_scalar!(maximum_scalar_i64, i64);
+    view_binop_scalar!(maximum_scalar_f64, f64);
+
+    view_binop_scalar!(minimum_scalar_i64, i64);
+    view_binop_scalar!(minimum_scalar_f64, f64);
+
+    view_binop_scalar!(fmax_scalar_i64, i64);
+    view_binop_scalar!(fmax_scalar_f64, f64);
+
+    view_binop_scalar!(fmin_scalar_i64, i64);
+    view_binop_scalar!(fmin_scalar_f64, f64);
+
+    view_binop_scalar!(atan2_scalar_i64, i64);
+    view_binop_scalar!(atan2_scalar_f64, f64);
+
+    view_binop_scalar!(hypot_scalar_i64, i64);
+    view_binop_scalar!(hypot_scalar_f64, f64);
+
+    view_binop_scalar!(copysign_scalar_i64, i64);
+    view_binop_scalar!(copysign_scalar_f64, f64);
+
+    view_binop_scalar!(nextafter_scalar_i64, i64);
+    view_binop_scalar!(nextafter_scalar_f64, f64);
+
+    view_binop_scalar!(logaddexp_scalar_i64, i64);
+    view_binop_scalar!(logaddexp_scalar_f64, f64);
+
+    view_binop_scalar!(logaddexp2_scalar_i64, i64);
+    view_binop_scalar!(logaddexp2_scalar_f64, f64);
+
+    view_binop_scalar!(float_power_scalar_i64, i64);
+    view_binop_scalar!(float_power_scalar_f64, f64);
+
     axis_fn!(axis_sum);
     axis_fn!(axis_mean);
     axis_fn!(axis_var);
@@ -305,6 +631,180 @@ impl NDArray {
         }
     }
 
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        match self {
+            NDArray::Owned(a) => a.view().shuffle(rng),
+            NDArray::View(a) => a.shuffle(rng),
+        }
+    }
+
+    pub fn select(&mut self, axis: usize, indices: &[usize]) -> NDArray {
+        NDArray::from(self.view().select(axis, indices))
+    }
+
+    fn common_shape(shapes: &[&[usize]]) -> Vec<usize> {
+        match broadcast_shape(shapes.iter().cloned()) {
+            Some(s) => s,
+            None => rynd_error!("Unable to broadcast arrays with shapes {:?}", shapes),
+        }
+    }
+
+    pub fn where_cond(cond: &mut NDArray, x: &mut NDArray, y: &mut NDArray) -> NDArray {
+        let shape = Self::common_shape(&[cond.shape(), x.shape(), y.shape()]);
+        let tp = x.dtype().promote(&y.dtype());
+
+        let mut x = x.as_dtype(tp.clone());
+        let mut y = y.as_dtype(tp);
+
+        NDArrayView::where_cond(
+            &cond.broadcast_to(&shape).view(), 
+            &x.broadcast_to(&shape).view(), 
+            &y.broadcast_to(&shape).view()
+        ).into()
+    }
+
+    // The first condition that holds decides the value, so conditions are applied from last to first
+    pub fn select_cond(conds: &mut [&mut NDArray], choices: &mut [&mut NDArray], default: &mut NDArray) -> NDArray {
+        if conds.len() != choices.len() {
+            rynd_error!("Expected the same number of conditions and choices ({} and {} given)", conds.len(), choices.len());
+        }
+
+        let mut res = NDArray::from(default.view());
+
+        for (c, x) in conds.iter_mut().zip(choices.iter_mut()).rev() {
+            res = NDArray::where_cond(c, x, &mut res);
+        }
+
+        res
+    }
+
+    pub fn choose(indices: &mut NDArray, choices: &mut [&mut NDArray], mode: TakeMode) -> NDArray {
+        if choices.is_empty() {
+            rynd_error!("Unable to choose from an empty list of arrays");
+        }
+
+        let mut shapes = choices.iter().map(|c| c.shape()).collect::<Vec<_>>();
+        shapes.push(indices.shape());
+
+        let shape = Self::common_shape(&shapes);
+
+        let tp = choices.iter().fold(choices[0].dtype(), |t, c| t.promote(&c.dtype()));
+
+        let mut converted = choices.iter_mut().map(|c| c.as_dtype(tp.clone())).collect::<Vec<_>>();
+        let views = converted.iter_mut().map(|c| c.broadcast_to(&shape).view()).collect::<Vec<_>>();
+
+        NDArrayView::choose(&indices.broadcast_to(&shape).view(), &views, mode).into()
+    }
+
+    // Every array is copied directly into its section of a single result of the promoted type
+    pub fn concatenate(arrays: &mut [&mut NDArray], axis: usize) -> NDArray {
+        if arrays.is_empty() {
+            rynd_error!("Unable to concatenate an empty list of arrays");
+        }
+
+        let first = arrays[0].shape().to_vec();
+        let mut shape = first.clone();
+        let mut tp = arrays[0].dtype();
+
+        shape[axis] = 0;
+
+        for a in arrays.iter() {
+            let s = a.shape();
+
+            if s.len() != first.len() || s.iter().zip(&first).enumerate().any(|(i, (x, y))| i != axis && x != y) {
+                rynd_error!("Unable to concatenate arrays of shape {:?} and {:?} over axis {}", first, s, axis);
+            }
+
+            shape[axis] += s[axis];
+            tp = tp.promote(&a.dtype());
+        }
+
+        let mut res = NDArrayOwned::new(tp, shape);
+        let target = res.view();
+        let mut offset = 0;
+
+        for a in arrays.iter_mut() {
+            let len = a.shape()[axis];
+
+            target.slice_axis(axis, Slice::from(offset..offset + len)).assign(&a.view());
+            offset += len;
+        }
+
+        res.into()
+    }
+
+    pub fn stack_all(arrays: &mut [&mut NDArray], axis: usize) -> NDArray {
+        if let Some(a) = arrays.iter().find(|a| a.shape() != arrays[0].shape()) {
+            rynd_error!("Unable to stack arrays of shape {:?} and {:?} over axis {}", arrays[0].shape(), a.shape(), axis);
+        }
+
+        let mut expanded = arrays.iter_mut().map(|a| a.expand_dims(axis)).collect::<Vec<_>>();
+
+        NDArray::concatenate(&mut expanded.iter_mut().collect::<Vec<_>>(), axis)
+    }
+
+    pub fn hstack(arrays: &mut [&mut NDArray]) -> NDArray {
+        let axis = if arrays.first().is_some_and(|a| a.shape().len() == 1) { 0 } else { 1 };
+
+        NDArray::concatenate(arrays, axis)
+    }
+
+    pub fn vstack(arrays: &mut [&mut NDArray]) -> NDArray {
+        let mut expanded = arrays.iter_mut().map(|a| match a.shape().len() {
+            1 => a.expand_dims(0),
+            _ => NDArray::from(a.view())
+        }).collect::<Vec<_>>();
+
+        NDArray::concatenate(&mut expanded.iter_mut().collect::<Vec<_>>(), 0)
+    }
+
+    pub fn dstack(arrays: &mut [&mut NDArray]) -> NDArray {
+        let mut expanded = arrays.iter_mut().map(|a| match a.shape().len() {
+            1 => a.expand_dims(0).expand_dims(2),
+            2 => a.expand_dims(2),
+            _ => NDArray::from(a.view())
+        }).collect::<Vec<_>>();
+
+        NDArray::concatenate(&mut expanded.iter_mut().collect::<Vec<_>>(), 2)
+    }
+
+    pub fn flip(&mut self, axes: &[usize]) -> NDArray {
+        let mut res = NDArray::from(self.view());
+
+        for axis in axes {
+            res = res.axis_reverse(*axis);
+        }
+
+        res
+    }
+
+    // Rotates from the first axis towards the second one, as in NumPy
+    pub fn rot90(&mut self, k: i64, a: usize, b: usize) -> NDArray {
+        match k.rem_euclid(4) {
+            0 => NDArray::from(self.view()),
+            1 => self.axis_reverse(b).swap_axes(a, b),
+            2 => self.axis_reverse(a).axis_reverse(b),
+            _ => self.swap_axes(a, b).axis_reverse(b),
+        }
+    }
+
+    pub fn roll(&mut self, shift: i64, axis: Option<usize>) -> NDArray {
+        let mut source = self.axis_source(axis);
+
+        let axis = axis.unwrap_or(0);
+        let len = source.shape()[axis];
+        let split = len - shift.rem_euclid(len.max(1) as i64) as usize;
+
+        let mut tail = source.slice_axis(axis, Slice::from(split..));
+        let mut head = source.slice_axis(axis, Slice::from(..split));
+        let mut res = NDArray::concatenate(&mut [&mut tail, &mut head], axis);
+
+        match self.shape() {
+            s if s.len() == res.shape().len() => res,
+            s => NDArray::from(res.reshape(s.to_vec()).view().owned()),
+        }
+    }
+
     pub fn stack(&mut self, other: &mut NDArray, axis: usize) -> NDArray {
         match (self, other) {
             (NDArray::Owned(a), NDArray::Owned(b)) => a.view().stack(&b.view(), axis).into(),
@@ -342,6 +842,30 @@ impl NDArray {
     unary_fn!(cosh);
     unary_fn!(sinh);
     unary_fn!(tanh);
+    unary_fn!(abs);
+    unary_fn!(sign);
+    unary_fn!(square);
+    unary_fn!(exp2);
+    unary_fn!(expm1);
+    unary_fn!(log1p);
+    unary_fn!(cbrt);
+    unary_fn!(reciprocal);
+
+    pub fn diag(&mut self, k: i64) -> NDArray {
+        NDArray::from(self.view().diag(k))
+    }
+
+    pub fn triu(&mut self, k: i64) -> NDArray {
+        NDArray::from(self.view().triu(k))
+    }
+
+    pub fn tril(&mut self, k: i64) -> NDArray {
+        NDArray::from(self.view().tril(k))
+    }
+
+    pub fn trace(&mut self, k: i64) -> NDArray {
+        NDArray::from(self.view().trace(k))
+    }
 
     pub fn clip(&mut self, low: f64, high: f64) -> NDArray {
         match self {
@@ -358,4 +882,301 @@ impl Display for NDArray {
             NDArray::View(v) => write!(f, "{}", v),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, ArrayD};
+
+    use super::*;
+    use crate::view::RawView;
+
+    fn ints(a: ArrayD<i64>) -> NDArray {
+        NDArrayOwned::from(a).into()
+    }
+
+    fn values(a: &mut NDArray) -> ArrayD<i64> {
+        a.view().to_i64()
+    }
+
+    #[test]
+    fn axis_views() {
+        let mut a = ints(ArrayD::from_shape_fn(vec![2, 1, 3], |i| (i[0] * 3 + i[2]) as i64));
+
+        let mut squeezed = a.squeeze(&[1]);
+        assert_eq!(values(&mut squeezed), array![[0, 1, 2], [3, 4, 5]].into_dyn());
+
+        assert_eq!(a.expand_dims(0).shape(), [1, 2, 1, 3]);
+        assert_eq!(a.expand_dims(3).shape(), [2, 1, 3, 1]);
+
+        let mut swapped = squeezed.swap_axes(0, 1);
+        assert_eq!(values(&mut swapped), array![[0, 3], [1, 4], [2, 5]].into_dyn());
+
+        let mut moved = a.move_axis(2, 0);
+        assert_eq!(moved.shape(), [3, 2, 1]);
+        assert_eq!(values(&mut moved).remove_axis(ndarray::Axis(2)), array![[0, 3], [1, 4], [2, 5]].into_dyn());
+        assert_eq!(a.move_axis(0, 2).shape(), [1, 3, 2]);
+    }
+
+    #[test]
+    fn joining() {
+        let mut a = ints(array![[1, 2], [3, 4]].into_dyn());
+        let mut b = ints(array![[5, 6]].into_dyn());
+        let mut c: NDArray = NDArrayOwned::from(array![[0.5], [1.5]].into_dyn()).into();
+
+        let mut res = NDArray::concatenate(&mut [&mut a, &mut b], 0);
+        assert_eq!(values(&mut res), array![[1, 2], [3, 4], [5, 6]].into_dyn());
+
+        // Mixed types are promoted
+        let mut res = NDArray::concatenate(&mut [&mut a, &mut c], 1);
+        assert!(res.dtype() == NDArrayType::Float);
+        assert_eq!(res.view().to_f64(), array![[1.0, 2.0, 0.5], [3.0, 4.0, 1.5]].into_dyn());
+
+        let mut copy = a.clone();
+        let mut res = NDArray::stack_all(&mut [&mut a, &mut copy], 2);
+        assert_eq!(res.shape(), [2, 2, 2]);
+        assert_eq!(values(&mut res), array![[[1, 1], [2, 2]], [[3, 3], [4, 4]]].into_dyn());
+
+        let mut x = ints(array![1, 2].into_dyn());
+        let mut y = ints(array![3, 4].into_dyn());
+        assert_eq!(values(&mut NDArray::hstack(&mut [&mut x, &mut y])), array![1, 2, 3, 4].into_dyn());
+        assert_eq!(values(&mut NDArray::vstack(&mut [&mut x, &mut y])), array![[1, 2], [3, 4]].into_dyn());
+        assert_eq!(values(&mut NDArray::dstack(&mut [&mut x, &mut y])), array![[[1, 3], [2, 4]]].into_dyn());
+    }
+
+    #[test]
+    fn broadcast_views() {
+        let mut a = ints(array![1, 2, 3].into_dyn());
+
+        let mut res = a.broadcast_to(&[2, 3]);
+        assert_eq!(values(&mut res), array![[1, 2, 3], [1, 2, 3]].into_dyn());
+
+        // Reversed axes have negative strides
+        let mut flipped = a.flip(&[0]);
+        let mut res = flipped.broadcast_to(&[2, 3]);
+        assert_eq!(values(&mut res), array![[3, 2, 1], [3, 2, 1]].into_dyn());
+
+        let mut col = ints(array![[1], [2]].into_dyn());
+        let mut col = col.flip(&[0]);
+        let mut res = col.broadcast_to(&[3, 2, 2]);
+        assert_eq!(values(&mut res).index_axis(ndarray::Axis(0), 2), array![[2, 2], [1, 1]].into_dyn());
+
+        let mut empty = ints(ArrayD::zeros(vec![0, 3]));
+        let mut empty = empty.flip(&[0, 1]);
+        assert_eq!(empty.broadcast_to(&[2, 0, 3]).shape(), [2, 0, 3]);
+    }
+
+    #[test]
+    fn views_of_broadcast_views() {
+        let mut a = ints(array![1, 2, 3].into_dyn());
+        let mut b = a.broadcast_to(&[2, 3]);
+        assert!(matches!(b.view(), NDArrayView::Int(RawView::Const(_))));
+
+        // Derived views keep reading through the zero strides and stay read-only
+        let mut sliced = b.slice_axis(1, Slice::from(1..));
+        assert!(matches!(sliced.view(), NDArrayView::Int(RawView::Const(_))));
+        assert_eq!(values(&mut sliced), array![[2, 3], [2, 3]].into_dyn());
+
+        let mut flipped = b.flip(&[1]);
+        assert_eq!(values(&mut flipped), array![[3, 2, 1], [3, 2, 1]].into_dyn());
+
+        let mut permuted = b.permute(&[1, 0]);
+        assert_eq!(values(&mut permuted), array![[1, 1], [2, 2], [3, 3]].into_dyn());
+
+        let mut diagonal = b.diagonal(1);
+        assert_eq!(values(&mut diagonal), array![2, 3].into_dyn());
+
+        let mut expanded = b.expand_dims(0);
+        assert_eq!(values(&mut expanded), array![[[1, 2, 3], [1, 2, 3]]].into_dyn());
+    }
+
+    #[test]
+    fn copies_own_their_data() {
+        let mut a = ints(array![1, 2, 3].into_dyn());
+        let b = a.broadcast_to(&[2, 3]);
+
+        let mut copy = b.copy();
+        assert!(matches!(copy, NDArray::Owned(_)));
+
+        copy.view().assign(&ints(array![[4, 5, 6], [7, 8, 9]].into_dyn()).view());
+        assert_eq!(values(&mut copy), array![[4, 5, 6], [7, 8, 9]].into_dyn());
+        assert_eq!(values(&mut a), array![1, 2, 3].into_dyn());
+    }
+
+    #[test]
+    fn repeats() {
+        let mut a = ints(array![[1, 2], [3, 4]].into_dyn());
+        assert_eq!(values(&mut a.repeat(&[2], None)), array![1, 1, 2, 2, 3, 3, 4, 4].into_dyn());
+        assert_eq!(values(&mut a.repeat(&[1, 2], Some(0))), array![[1, 2], [3, 4], [3, 4]].into_dyn());
+        assert_eq!(values(&mut a.repeat(&[0, 3], Some(1))), array![[2, 2, 2], [4, 4, 4]].into_dyn());
+    }
+
+    #[test]
+    fn flips_rotations_and_rolls() {
+        let mut a = ints(array![[1, 2, 3], [4, 5, 6]].into_dyn());
+
+        assert_eq!(values(&mut a.flip(&[1])), array![[3, 2, 1], [6, 5, 4]].into_dyn());
+        assert_eq!(values(&mut a.flip(&[0, 1])), array![[6, 5, 4], [3, 2, 1]].into_dyn());
+
+        assert_eq!(values(&mut a.rot90(1, 0, 1)), array![[3, 6], [2, 5], [1, 4]].into_dyn());
+        assert_eq!(values(&mut a.rot90(2, 0, 1)), array![[6, 5, 4], [3, 2, 1]].into_dyn());
+        assert_eq!(values(&mut a.rot90(-1, 0, 1)), array![[4, 1], [5, 2], [6, 3]].into_dyn());
+        assert_eq!(values(&mut a.rot90(4, 0, 1)), values(&mut a));
+
+        assert_eq!(values(&mut a.roll(1, None)), array![[6, 1, 2], [3, 4, 5]].into_dyn());
+        assert_eq!(values(&mut a.roll(1, Some(1))), array![[3, 1, 2], [6, 4, 5]].into_dyn());
+        assert_eq!(values(&mut a.roll(7, Some(1))), array![[3, 1, 2], [6, 4, 5]].into_dyn());
+        assert_eq!(values(&mut a.roll(-1, Some(0))), array![[4, 5, 6], [1, 2, 3]].into_dyn());
+    }
+
+    #[test]
+    fn insertions_and_deletions() {
+        let mut a = ints(ArrayD::from_shape_fn(vec![3, 2], |i| (i[0] * 2 + i[1]) as i64));
+        let mut pair = ints(array![10, 20].into_dyn());
+        let mut column = ints(array![7, 8, 9].into_dyn());
+        let mut rows = ints(array![[10, 11], [12, 13]].into_dyn());
+        let mut scalar = ints(ArrayD::from_elem(vec![], 5));
+
+        // Scalar indices insert every value, index lists insert one value per index
+        assert_eq!(values(&mut a.insert_at(1, &mut pair, None)), array![0, 10, 20, 1, 2, 3, 4, 5].into_dyn());
+        assert_eq!(values(&mut a.insert(&[1, 3], &mut pair, None)), array![0, 10, 1, 2, 20, 3, 4, 5].into_dyn());
+        assert_eq!(values(&mut a.insert(&[6, 0], &mut pair, None)), array![20, 0, 1, 2, 3, 4, 5, 10].into_dyn());
+
+        assert_eq!(values(&mut a.insert_at(1, &mut column, Some(1))), array![[0, 7, 1], [2, 8, 3], [4, 9, 5]].into_dyn());
+        assert_eq!(values(&mut a.insert_at(1, &mut rows, Some(0))), array![[0, 1], [10, 11], [12, 13], [2, 3], [4, 5]].into_dyn());
+        assert_eq!(values(&mut a.insert_at(2, &mut scalar, Some(1))), array![[0, 1, 5], [2, 3, 5], [4, 5, 5]].into_dyn());
+        assert_eq!(values(&mut a.insert(&[0, 0], &mut scalar, Some(0))), array![[5, 5], [5, 5], [0, 1], [2, 3], [4, 5]].into_dyn());
+
+        // Inserted values take the type of the array
+        let mut floats: NDArray = NDArrayOwned::from(array![1.7].into_dyn()).into();
+        let mut res = a.insert_at(0, &mut floats, None);
+        assert!(res.dtype() == NDArrayType::Int);
+        assert_eq!(values(&mut res), array![1, 0, 1, 2, 3, 4, 5].into_dyn());
+
+        assert_eq!(values(&mut a.delete(&[0, 2, 0], Some(0))), array![[2, 3]].into_dyn());
+        assert_eq!(values(&mut a.delete(&[1, 4], None)), array![0, 2, 3, 5].into_dyn());
+
+        assert_eq!(values(&mut a.append(&mut rows, Some(0))), array![[0, 1], [2, 3], [4, 5], [10, 11], [12, 13]].into_dyn());
+        assert_eq!(values(&mut a.append(&mut pair, None)), array![0, 1, 2, 3, 4, 5, 10, 20].into_dyn());
+    }
+
+    #[test]
+    fn scatter_with_aliasing() {
+        let mut a = ints(array![1, 2, 3].into_dyn());
+        let mut idx = ints(array![1, 2].into_dyn());
+        let mut head = a.slice_axis(0, Slice::from(0..2));
+
+        // Values are read before any write, as if they were copied
+        a.scatter(&mut [&mut idx], &mut head, ScatterOp::Assign);
+        assert_eq!(values(&mut a), array![1, 1, 2].into_dyn());
+
+        let mut a = ints(array![2, 0, 1].into_dyn());
+        let mut same = a.clone();
+        let (mut idx, mut vals) = (NDArray::from(a.view()), NDArray::from(same.view()));
+        a.scatter(&mut [&mut idx], &mut vals, ScatterOp::Assign);
+        assert_eq!(values(&mut a), array![0, 1, 2].into_dyn());
+
+        let mut idx = ints(array![0, 0, 2].into_dyn());
+        let mut vals = NDArray::from(a.view());
+        a.scatter(&mut [&mut idx], &mut vals, ScatterOp::Add);
+        assert_eq!(values(&mut a), array![1, 1, 4].into_dyn());
+    }
+
+    #[test]
+    fn put_along_axis_with_aliasing() {
+        let mut a = ints(array![[10, 30, 20], [60, 40, 50]].into_dyn());
+        let mut idx = ints(array![[2, 0], [1, 2]].into_dyn());
+        let mut vals = a.slice_axis(1, Slice::from(0..2));
+
+        a.put_along_axis(&mut idx, &mut vals, 1);
+        assert_eq!(values(&mut a), array![[30, 30, 10], [60, 60, 40]].into_dyn());
+    }
+
+    #[test]
+    fn conditions_promote_and_broadcast() {
+        let mut cond: NDArray = NDArrayOwned::from(array![[true], [false]].into_dyn()).into();
+        let mut x = ints(array![1, 2, 3].into_dyn());
+        let mut y: NDArray = NDArrayOwned::from(array![0.5].into_dyn()).into();
+
+        let mut res = NDArray::where_cond(&mut cond, &mut x, &mut y);
+        assert!(matches!(res.dtype(), NDArrayType::Float));
+        assert_eq!(res.view().to_f64(), array![[1.0, 2.0, 3.0], [0.5, 0.5, 0.5]].into_dyn());
+
+        let mut idx = ints(array![[0, 1], [1, 0]].into_dyn());
+        let mut res = NDArray::choose(&mut idx, &mut [&mut x.slice_axis(0, Slice::from(0..2)), &mut y], TakeMode::Raise);
+        assert!(matches!(res.dtype(), NDArrayType::Float));
+        assert_eq!(res.view().to_f64(), array![[1.0, 0.5], [0.5, 2.0]].into_dyn());
+    }
+
+    #[test]
+    fn elementwise_extremes() {
+        let mut a = ints(array![1, 5, 3].into_dyn());
+        let mut b: NDArray = NDArrayOwned::from(array![[2.0, f64::NAN, 0.0], [4.0, 4.0, 4.0]].into_dyn()).into();
+        let (a, b) = (a.view(), b.view());
+
+        let mut res: NDArray = a.maximum(&b).into();
+        let res = res.view().to_f64();
+        assert_eq!(res.shape(), [2, 3]);
+        assert!(res[[0, 1]].is_nan());
+        assert_eq!((res[[0, 0]], res[[0, 2]]), (2.0, 3.0));
+        assert_eq!(res.index_axis(ndarray::Axis(0), 1), array![4.0, 5.0, 4.0].into_dyn());
+
+        let mut res: NDArray = a.fmin(&b).into();
+        assert_eq!(res.view().to_f64(), array![[1.0, 5.0, 0.0], [1.0, 4.0, 3.0]].into_dyn());
+
+        let mut res: NDArray = a.minimum_scalar_i64(2, false).into();
+        assert_eq!(values(&mut res), array![1, 2, 2].into_dyn());
+
+        let mut res: NDArray = b.fmax_scalar_f64(3.0, true).into();
+        assert_eq!(res.view().to_f64(), array![[3.0, 3.0, 3.0], [4.0, 4.0, 4.0]].into_dyn());
+    }
+
+    #[test]
+    fn unary_functions() {
+        let mut a = ints(array![-3, 0, 2, i64::MIN].into_dyn());
+        let mut f: NDArray = NDArrayOwned::from(array![-2.0, -0.0, 0.0, 8.0].into_dyn()).into();
+        let mut b: NDArray = NDArrayOwned::from(array![true, false].into_dyn()).into();
+        let (a, f, b) = (a.view(), f.view(), b.view());
+
+        // Integers wrap on overflow instead of panicking
+        let mut res: NDArray = a.abs().into();
+        assert_eq!(values(&mut res), array![3, 0, 2, i64::MIN].into_dyn());
+
+        let mut res: NDArray = a.square().into();
+        assert_eq!(values(&mut res), array![9, 0, 4, 0].into_dyn());
+
+        let mut res: NDArray = a.sign().into();
+        assert_eq!(values(&mut res), array![-1, 0, 1, -1].into_dyn());
+
+        let mut res: NDArray = f.sign().into();
+        assert_eq!(res.view().to_f64(), array![-1.0, 0.0, 0.0, 1.0].into_dyn());
+
+        let mut res: NDArray = f.abs().into();
+        assert_eq!(res.view().to_f64(), array![2.0, 0.0, 0.0, 8.0].into_dyn());
+
+        // Booleans are left unchanged
+        for mut res in [b.abs(), b.sign(), b.square()].map(NDArray::from) {
+            assert!(matches!(res.dtype(), NDArrayType::Bool));
+            assert_eq!(values(&mut res), array![1, 0].into_dyn());
+        }
+
+        // Float functions promote integers and booleans
+        let mut res: NDArray = f.cbrt().into();
+        assert_eq!(res.view().to_f64(), array![-2.0f64.cbrt(), -0.0, 0.0, 2.0].into_dyn());
+
+        let mut res: NDArray = a.reciprocal().into();
+        assert!(matches!(res.dtype(), NDArrayType::Float));
+        assert_eq!(res.view().to_f64()[0], -1.0 / 3.0);
+
+        let mut res: NDArray = b.exp2().into();
+        assert_eq!(res.view().to_f64(), array![2.0, 1.0].into_dyn());
+
+        let mut small: NDArray = NDArrayOwned::from(array![1e-20].into_dyn()).into();
+        let small = small.view();
+        let mut res: NDArray = small.expm1().into();
+        assert_eq!(res.view().to_f64()[0], 1e-20);
+
+        let mut res: NDArray = small.log1p().into();
+        assert_eq!(res.view().to_f64()[0], 1e-20);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::array::NDArray;
+use std::os::raw::c_void;
+
+use crate::{array::NDArray, memory::is_readonly, owned::NDArrayType};
 
 #[macro_export]
 macro_rules! rynd_error {
@@ -32,6 +34,12 @@ pub fn rynd_permute_check(arr: &NDArray, perm: &[usize]) {
     }
 }
 
+pub fn rynd_writable_check(ptr: *const c_void) {
+    if is_readonly(ptr) {
+        rynd_error!("Unable to modify a read-only array (broadcast views cannot be written to)");
+    }
+}
+
 pub fn rynd_matmul_check(a: &NDArray, b: &NDArray) {
     let shape_a = a.shape();
     let shape_b = b.shape();
@@ -49,6 +57,39 @@ pub fn rynd_matmul_check(a: &NDArray, b: &NDArray) {
     }
 }
 
+pub fn rynd_square_check(arr: &NDArray) {
+    let shape = arr.shape();
+
+    if shape.len() != 2 || shape[0] != shape[1] {
+        rynd_error!("Expected a square matrix (shape is {:?})", shape);
+    }
+}
+
+pub fn rynd_solve_check(a: &NDArray, b: &NDArray) {
+    let shape_a = a.shape();
+    let shape_b = b.shape();
+
+    if shape_b.is_empty() || shape_b.len() > 2 {
+        rynd_error!("Expected right-hand side of linear system to be of dimension 1 or 2 (shape is {:?})", shape_b);
+    }
+
+    if shape_a[0] != shape_b[0] {
+        rynd_error!("Incompatible array shapes for linear system ({:?} x {:?})", shape_a, shape_b);
+    }
+}
+
+pub fn rynd_index_array_check(arr: &NDArray) {
+    if !matches!(arr.dtype(), NDArrayType::Int) {
+        rynd_error!("Expected an Int array of indices");
+    }
+}
+
+pub fn rynd_condition_check(arr: &NDArray) {
+    if !matches!(arr.dtype(), NDArrayType::Bool) {
+        rynd_error!("Expected a Bool array as condition");
+    }
+}
+
 pub fn rynd_normalize_dim(arr: &NDArray, dim: &mut i64) {
     let shape = arr.shape();
     let orig = *dim;
@@ -62,6 +103,32 @@ pub fn rynd_normalize_dim(arr: &NDArray, dim: &mut i64) {
     }
 }
 
+// Negative indices count from the end. Insertion points may also be equal to the length
+pub fn rynd_normalize_index(index: i64, len: usize, allow_end: bool) -> usize {
+    let limit = len as i64 + allow_end as i64;
+    let idx = if index < 0 { index + len as i64 } else { index };
+
+    if idx < 0 || idx >= limit {
+        rynd_error!("Index {} is out of bounds for dimension of size {}", index, len);
+    }
+
+    idx as usize
+}
+
+// Used for positions where a new axis can be inserted (one more than the number of dimensions)
+pub fn rynd_normalize_new_dim(arr: &NDArray, dim: &mut i64) {
+    let num_dims = arr.shape().len() as i64 + 1;
+    let orig = *dim;
+
+    if *dim < 0 {
+        *dim += num_dims;
+    }
+
+    if *dim < 0 || *dim >= num_dims {
+        rynd_error!("Position {} is invalid for a new dimension (shape is {:?})", orig, arr.shape());
+    }
+}
+
 pub fn rynd_dims_check(arr: &NDArray, min_dims: Option<usize>, max_dims: Option<usize>) {
     let shape = arr.shape();
 
@@ -78,39 +145,9 @@ pub fn rynd_dims_check(arr: &NDArray, min_dims: Option<usize>, max_dims: Option<
     }
 }
 
-pub fn rynd_slice_check(arr: &NDArray, start: isize, end: isize, step: isize, dim_idx: usize) {
-    let shape = arr.shape();
-    let dim = shape[dim_idx];
-
+// Slice bounds are clamped to the dimension like in Python, so only the step can be invalid
+pub fn rynd_slice_check(step: isize) {
     if step == 0 {
         rynd_error!("Slice step cannot be 0");
     }
-
-    if start >= 0 {
-        if start as usize >= dim {
-            rynd_error!("Slice start out of bounds for dimension {} ({} >= {})", dim_idx, start, dim);
-        }
-
-    } else {
-        let adjusted = 1 + dim as isize + start;
-
-        if adjusted < 0 || adjusted as usize >= dim {
-            rynd_error!("Negative slice start out of bounds for dimension {} ({} given, size is {})", dim_idx, start, dim);
-        }
-
-    }
-
-    if end >= 0 {
-        if end as usize > dim {
-            rynd_error!("Slice end out of bounds for dimension {} ({} >= {})", dim_idx, end, dim);
-        }
-
-    } else {
-        let adjusted = 1 + dim as isize + end;
-
-        if adjusted < 0 || adjusted as usize > dim {
-            rynd_error!("Negative slice end out of bounds for dimension {} ({} given, size is {})", dim_idx, end, dim);
-        }
-
-    }
 }
\ No newline at end of file
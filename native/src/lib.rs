@@ -1,11 +1,15 @@
 use std::{io::Write, os::raw::c_void};
 
+use algorithms::{PadMode, ScatterOp, TakeMode};
 use array::NDArray;
-use error::{rynd_dims_check, rynd_matmul_check, rynd_normalize_dim, rynd_permute_check, rynd_slice_check};
-use memory::{free_array_ptr, ptr_to_ref, register_and_leak, register_view};
-use ndarray::{Array1, Slice};
+use error::{rynd_condition_check, rynd_dims_check, rynd_index_array_check, rynd_matmul_check, rynd_normalize_dim, rynd_normalize_index, rynd_normalize_new_dim, rynd_permute_check, rynd_slice_check, rynd_solve_check, rynd_square_check, rynd_writable_check};
+use memory::{free_array_ptr, free_list_ptr, list_ptr_to_ref, ptr_to_ref, register_and_leak, register_and_leak_list, register_view, set_readonly};
+use ndarray::{Array1, Ix1, Ix2, Slice};
+use rand_distr::{Bernoulli, Beta, Binomial, Distribution, Exp, Gamma, LogNormal, Poisson, Uniform};
 use owned::{NDArrayOwned, NDArrayType};
-use rynaffi::{ryna_ffi_function, FFIArgs, FFIReturn};
+use random::{advance_generator, choice_indices, create_generator, dirichlet, distribution, free_generator_ptr, generator_ptr_to_ref, multivariate_normal, permutation, rng_from_ffi, seed_generator};
+use slicing::normalize_slice;
+use rynaffi::{ryna_ffi_function, FFIArgs, FFIReturn, FFIValue};
 
 mod owned;
 mod view;
@@ -13,8 +17,13 @@ mod array;
 mod error;
 mod memory;
 mod algorithms;
+mod linalg;
+mod random;
+mod slicing;
 
-// Memory management
+// Memory management. The exported malloc and free replace the system allocator's symbols, so they
+// are left out of test builds, where the test harness would call them instead
+#[cfg(not(test))]
 ryna_ffi_function!(malloc(args, out) {
     let size = args[0].as_i64() as usize;
     let layout = std::alloc::Layout::array::<i64>(size).expect("Invalid layout");
@@ -23,6 +32,7 @@ ryna_ffi_function!(malloc(args, out) {
     unsafe { *out = (ptr as *const c_void).into(); }
 });
 
+#[cfg(not(test))]
 ryna_ffi_function!(free(args, _out) {
     let ptr = args[0].as_ptr() as *mut u8;
     let size = args[1].as_i64() as usize;
@@ -58,10 +68,91 @@ ryna_ffi_function!(create_array(args, out) {
     unsafe { *out = register_and_leak(array).into(); }
 });
 
+ryna_ffi_function!(full_array(args, out) {
+    let tp = args[0].as_i64() as usize;
+    let num_dims = args[2].as_i64() as usize;
+    let shape = args[3..3 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let tp = match tp.try_into() {
+        Ok(t) => t,
+        Err(_) => rynd_error!("Invalid array type {tp}"),
+    };
+
+    let array = match args[1] {
+        FFIValue::Int(v) => NDArrayOwned::full_i64(tp, shape, v),
+        FFIValue::Float(v) => NDArrayOwned::full_f64(tp, shape, v),
+        _ => rynd_error!("Fill value must be a number")
+    };
+
+    unsafe { *out = register_and_leak(Box::new(array.into())).into(); }
+});
+
+ryna_ffi_function!(ones_array(args, out) {
+    let tp = args[0].as_i64() as usize;
+    let num_dims = args[1].as_i64() as usize;
+    let shape = args[2..2 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let array = match tp.try_into() {
+        Ok(t) => Box::new(NDArrayOwned::ones(t, shape).into()),
+        Err(_) => rynd_error!("Invalid array type {tp}"),
+    };
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(empty_array(args, out) {
+    let tp = args[0].as_i64() as usize;
+    let num_dims = args[1].as_i64() as usize;
+    let shape = args[2..2 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let array = match tp.try_into() {
+        Ok(t) => Box::new(NDArrayOwned::empty(t, shape).into()),
+        Err(_) => rynd_error!("Invalid array type {tp}"),
+    };
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(zeros_like_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    let array = Box::new(NDArray::new(a.dtype(), a.shape().to_vec()));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(ones_like_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    let array = Box::new(NDArrayOwned::ones(a.dtype(), a.shape().to_vec()).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(empty_like_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    let array = Box::new(NDArrayOwned::empty(a.dtype(), a.shape().to_vec()).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(full_like_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    let array = match args[1] {
+        FFIValue::Int(v) => NDArrayOwned::full_i64(a.dtype(), a.shape().to_vec(), v),
+        FFIValue::Float(v) => NDArrayOwned::full_f64(a.dtype(), a.shape().to_vec(), v),
+        _ => rynd_error!("Fill value must be a number")
+    };
+
+    unsafe { *out = register_and_leak(Box::new(array.into())).into(); }
+});
+
 ryna_ffi_function!(copy_array(args, out) {
     let a = ptr_to_ref(args[0].as_ptr());
 
-    let res = Box::new(a.clone());
+    let res = Box::new(a.copy());
 
     unsafe { *out = register_and_leak(res).into(); }
 });
@@ -72,6 +163,39 @@ ryna_ffi_function!(free_array(args, _out) {
     free_array_ptr(ptr);
 });
 
+// Array lists
+ryna_ffi_function!(create_list(_args, out) {
+    unsafe { *out = register_and_leak_list(vec![]).into(); }
+});
+
+// Arrays pushed to a list are not copied, they are still owned by the caller
+ryna_ffi_function!(list_push(args, _out) {
+    let list = list_ptr_to_ref(args[0].as_ptr());
+
+    list.push(args[1].as_ptr());
+});
+
+ryna_ffi_function!(list_len(args, out) {
+    let list = list_ptr_to_ref(args[0].as_ptr());
+
+    unsafe { *out = (list.len() as i64).into(); }
+});
+
+ryna_ffi_function!(list_get(args, out) {
+    let list = list_ptr_to_ref(args[0].as_ptr());
+    let idx = args[1].as_i64();
+
+    if idx < 0 || idx as usize >= list.len() {
+        rynd_error!("List index {} out of range (length is {})", idx, list.len());
+    }
+
+    unsafe { *out = list[idx as usize].into(); }
+});
+
+ryna_ffi_function!(free_list(args, _out) {
+    free_list_ptr(args[0].as_ptr());
+});
+
 // Operators
 macro_rules! binop_rynd_ffi {
     ($function: ident, $name: ident) => {
@@ -101,9 +225,22 @@ binop_rynd_ffi!(lt_arrays, lt);
 binop_rynd_ffi!(gt_arrays, gt);
 binop_rynd_ffi!(leq_arrays, leq);
 binop_rynd_ffi!(geq_arrays, geq);
+binop_rynd_ffi!(maximum_arrays, maximum);
+binop_rynd_ffi!(minimum_arrays, minimum);
+binop_rynd_ffi!(fmax_arrays, fmax);
+binop_rynd_ffi!(fmin_arrays, fmin);
+binop_rynd_ffi!(atan2_arrays, atan2);
+binop_rynd_ffi!(hypot_arrays, hypot);
+binop_rynd_ffi!(copysign_arrays, copysign);
+binop_rynd_ffi!(nextafter_arrays, nextafter);
+binop_rynd_ffi!(logaddexp_arrays, logaddexp);
+binop_rynd_ffi!(logaddexp2_arrays, logaddexp2);
+binop_rynd_ffi!(float_power_arrays, float_power);
 binop_rynd_ffi!(index_arrays, index);
 
 ryna_ffi_function!(assign_arrays(args, _out) {
+    rynd_writable_check(args[0].as_ptr());
+
     let a = ptr_to_ref(args[0].as_ptr());
     let b = ptr_to_ref(args[1].as_ptr());
 
@@ -115,6 +252,8 @@ ryna_ffi_function!(assign_arrays(args, _out) {
 });   
 
 ryna_ffi_function!(assign_arrays_mask(args, _out) {
+    rynd_writable_check(args[0].as_ptr());
+
     let a = ptr_to_ref(args[0].as_ptr());
     let b = ptr_to_ref(args[1].as_ptr());
     let m = ptr_to_ref(args[2].as_ptr());
@@ -164,6 +303,17 @@ binop_rynd_scalar_ffi!(lt_array_scalar, lt_scalar_i64, lt_scalar_f64);
 binop_rynd_scalar_ffi!(gt_array_scalar, gt_scalar_i64, gt_scalar_f64);
 binop_rynd_scalar_ffi!(leq_array_scalar, leq_scalar_i64, leq_scalar_f64);
 binop_rynd_scalar_ffi!(geq_array_scalar, geq_scalar_i64, geq_scalar_f64);
+binop_rynd_scalar_ffi!(maximum_array_scalar, maximum_scalar_i64, maximum_scalar_f64);
+binop_rynd_scalar_ffi!(minimum_array_scalar, minimum_scalar_i64, minimum_scalar_f64);
+binop_rynd_scalar_ffi!(fmax_array_scalar, fmax_scalar_i64, fmax_scalar_f64);
+binop_rynd_scalar_ffi!(fmin_array_scalar, fmin_scalar_i64, fmin_scalar_f64);
+binop_rynd_scalar_ffi!(atan2_array_scalar, atan2_scalar_i64, atan2_scalar_f64);
+binop_rynd_scalar_ffi!(hypot_array_scalar, hypot_scalar_i64, hypot_scalar_f64);
+binop_rynd_scalar_ffi!(copysign_array_scalar, copysign_scalar_i64, copysign_scalar_f64);
+binop_rynd_scalar_ffi!(nextafter_array_scalar, nextafter_scalar_i64, nextafter_scalar_f64);
+binop_rynd_scalar_ffi!(logaddexp_array_scalar, logaddexp_scalar_i64, logaddexp_scalar_f64);
+binop_rynd_scalar_ffi!(logaddexp2_array_scalar, logaddexp2_scalar_i64, logaddexp2_scalar_f64);
+binop_rynd_scalar_ffi!(float_power_array_scalar, float_power_scalar_i64, float_power_scalar_f64);
 
 ryna_ffi_function!(len(args, out) {
     let a = ptr_to_ref(args[0].as_ptr());
@@ -182,6 +332,8 @@ ryna_ffi_function!(shape(args, out) {
 ryna_ffi_function!(assign_array_scalar(args, _out) {
     use rynaffi::FFIValue;
 
+    rynd_writable_check(args[0].as_ptr());
+
     let a = ptr_to_ref(args[0].as_ptr());
     
     match args[1] {
@@ -194,6 +346,8 @@ ryna_ffi_function!(assign_array_scalar(args, _out) {
 ryna_ffi_function!(assign_array_scalar_mask(args, _out) {
     use rynaffi::FFIValue;
 
+    rynd_writable_check(args[0].as_ptr());
+
     let a = ptr_to_ref(args[0].as_ptr());
     let m = ptr_to_ref(args[2].as_ptr());
 
@@ -238,12 +392,165 @@ ryna_ffi_function!(iota(args, out) {
     unsafe { *out = register_and_leak(array).into(); }
 });
 
+fn ffi_number(value: &FFIValue) -> f64 {
+    match value {
+        FFIValue::Int(v) => *v as f64,
+        FFIValue::Float(v) => *v,
+        FFIValue::Pointer(_) => rynd_error!("Expected a numeric argument"),
+    }
+}
+
+ryna_ffi_function!(arange(args, out) {
+    let array = match (&args[0], &args[1], &args[2]) {
+        (FFIValue::Int(f), FFIValue::Int(t), FFIValue::Int(s)) => {
+            if *s == 0 {
+                rynd_error!("Range step cannot be 0");
+            }
+
+            NDArrayOwned::arange_i64(*f, *t, *s)
+        },
+
+        _ => {
+            let (f, t, s) = (ffi_number(&args[0]), ffi_number(&args[1]), ffi_number(&args[2]));
+
+            if s == 0.0 {
+                rynd_error!("Range step cannot be 0");
+            }
+
+            if !(f.is_finite() && t.is_finite() && s.is_finite()) {
+                rynd_error!("Range bounds and step must be finite");
+            }
+
+            NDArrayOwned::arange_f64(f, t, s)
+        }
+    };
+
+    unsafe { *out = register_and_leak(Box::new(array.into())).into(); }
+});
+
 ryna_ffi_function!(linspace(args, out) {
-    let f = args[0].as_i64();
-    let t = args[1].as_i64();
-    let s = args[2].as_i64() as usize;
+    let start = ffi_number(&args[0]);
+    let stop = ffi_number(&args[1]);
+    let num = args[2].as_i64();
+    let endpoint = args[3].as_i64() != 0;
+
+    if num < 0 {
+        rynd_error!("Number of samples must be non-negative ({} given)", num);
+    }
+
+    let array = Box::new(NDArrayOwned::linspace(start, stop, num as usize, endpoint).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(logspace(args, out) {
+    let start = ffi_number(&args[0]);
+    let stop = ffi_number(&args[1]);
+    let num = args[2].as_i64();
+    let endpoint = args[3].as_i64() != 0;
+    let base = ffi_number(&args[4]);
+
+    if num < 0 {
+        rynd_error!("Number of samples must be non-negative ({} given)", num);
+    }
+
+    let array = Box::new(NDArrayOwned::logspace(start, stop, num as usize, endpoint, base).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(geomspace(args, out) {
+    let start = ffi_number(&args[0]);
+    let stop = ffi_number(&args[1]);
+    let num = args[2].as_i64();
+    let endpoint = args[3].as_i64() != 0;
+
+    if num < 0 {
+        rynd_error!("Number of samples must be non-negative ({} given)", num);
+    }
+
+    if start == 0.0 || stop == 0.0 {
+        rynd_error!("Geometric sequence cannot include zero");
+    }
+
+    if start.signum() != stop.signum() {
+        rynd_error!("Geometric sequence bounds must have the same sign ({} and {} given)", start, stop);
+    }
+
+    let array = Box::new(NDArrayOwned::geomspace(start, stop, num as usize, endpoint).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(eye_array(args, out) {
+    let tp = args[0].as_i64() as usize;
+    let n = args[1].as_i64() as usize;
+    let m = args[2].as_i64() as usize;
+    let k = args[3].as_i64();
+
+    let array = match tp.try_into() {
+        Ok(t) => Box::new(NDArrayOwned::eye(t, n, m, k).into()),
+        Err(_) => rynd_error!("Invalid array type {tp}"),
+    };
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(diag_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let k = args[1].as_i64();
+
+    rynd_dims_check(arr, Some(1), Some(2));
+
+    let array = Box::new(arr.diag(k));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(diagonal_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let k = args[1].as_i64();
+
+    rynd_dims_check(arr, Some(2), Some(2));
+
+    let res = Box::new(arr.diagonal(k));
+    let view_ptr = register_and_leak(res);
+
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
+ryna_ffi_function!(triu_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let k = args[1].as_i64();
+
+    rynd_dims_check(arr, Some(2), None);
+
+    let array = Box::new(arr.triu(k));
 
-    let array = Box::new(NDArrayOwned::linspace(f, t, s).into());
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(tril_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let k = args[1].as_i64();
+
+    rynd_dims_check(arr, Some(2), None);
+
+    let array = Box::new(arr.tril(k));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(trace_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let k = args[1].as_i64();
+
+    rynd_dims_check(arr, Some(2), Some(2));
+
+    let array = Box::new(arr.trace(k));
 
     unsafe { *out = register_and_leak(array).into(); }
 });
@@ -262,6 +569,13 @@ ryna_ffi_function!(reshape_array(args, out) {
     unsafe { *out = view_ptr.into(); }
 });
 
+// Missing bounds are used for open-ended slices
+fn ffi_slice(arr: &NDArray, start: Option<isize>, end: Option<isize>, step: isize, dim_idx: usize) -> Slice {
+    rynd_slice_check(step);
+
+    normalize_slice(start, end, step, arr.shape()[dim_idx])
+}
+
 ryna_ffi_function!(slice_array(args, out) {
     let arr_ptr = args[0].as_ptr();
     let arr = ptr_to_ref(arr_ptr);
@@ -274,15 +588,7 @@ ryna_ffi_function!(slice_array(args, out) {
         .collect::<Vec<_>>()
         .chunks_exact(3)
         .zip(arr.shape().iter().enumerate())
-        .map(|(s, (d_idx, d))| {
-            rynd_slice_check(arr, s[0], s[1], s[2], d_idx);
-
-            let start = if s[0] >= 0 { s[0] } else { 1 + *d as isize + s[0] };
-            let end = if s[1] >= 0 { s[1] } else { 1 + *d as isize + s[1] };
-            let step = s[2];
-
-            Slice::new(start, Some(end), step)
-        })
+        .map(|(s, (d_idx, _))| ffi_slice(arr, Some(s[0]), Some(s[1]), s[2], d_idx))
         .collect::<Vec<_>>();
 
     let res = Box::new(arr.slice(slices));
@@ -293,6 +599,124 @@ ryna_ffi_function!(slice_array(args, out) {
     unsafe { *out = view_ptr.into(); }
 });
 
+// Every index is a kind followed by up to three values. Kinds are
+//  0: slice (start, end, step)
+//  1: slice without end (start, _, step)
+//  2: slice without start (_, end, step)
+//  3: whole dimension (_, _, step)
+//  4: integer (index, _, _), which removes the dimension
+//  5: new dimension of size 1
+//  6: ellipsis, which covers every dimension not indexed otherwise
+fn index_view(arr: &mut NDArray, specs: &[(i64, isize, isize, isize)]) -> NDArray {
+    let num_dims = arr.shape().len();
+    let indexed = specs.iter().filter(|s| (0..=4).contains(&s.0)).count();
+    let ellipses = specs.iter().filter(|s| s.0 == 6).count();
+
+    if ellipses > 1 {
+        rynd_error!("An index can only have a single ellipsis");
+    }
+
+    if indexed > num_dims {
+        rynd_error!("Too many indices for array of shape {:?} ({} given)", arr.shape(), indexed);
+    }
+
+    let mut slices = vec![];
+    let mut removed = vec![];
+    let mut new_axes = vec![];
+    let mut out_dims = 0;
+
+    for &(kind, a, b, step) in specs {
+        let d_idx = slices.len();
+        let dim = arr.shape().get(d_idx).cloned().unwrap_or(0);
+
+        match kind {
+            0..=3 => {
+                let start = if kind == 0 || kind == 1 { Some(a) } else { None };
+                let end = if kind == 0 || kind == 2 { Some(b) } else { None };
+
+                slices.push(ffi_slice(arr, start, end, step, d_idx));
+                out_dims += 1;
+            },
+
+            4 => {
+                let idx = rynd_normalize_index(a as i64, dim, false) as isize;
+
+                slices.push(Slice::new(idx, Some(idx + 1), 1));
+                removed.push(d_idx);
+            },
+
+            5 => {
+                new_axes.push(out_dims);
+                out_dims += 1;
+            },
+
+            6 => {
+                for _ in 0..num_dims - indexed {
+                    slices.push(Slice::new(0, None, 1));
+                    out_dims += 1;
+                }
+            },
+
+            _ => rynd_error!("Invalid index kind {kind}")
+        }
+    }
+
+    let mut res = arr.slice(slices).squeeze(&removed);
+
+    for axis in new_axes {
+        res = res.expand_dims(axis);
+    }
+
+    res
+}
+
+// Every index takes four arguments (see index_view)
+ryna_ffi_function!(index_view_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let num_idx = args[1].as_i64() as usize;
+
+    let specs = args[2..2 + num_idx * 4].chunks_exact(4)
+        .map(|s| (s[0].as_i64(), s[1].as_i64() as isize, s[2].as_i64() as isize, s[3].as_i64() as isize))
+        .collect::<Vec<_>>();
+
+    let view_ptr = register_and_leak(Box::new(index_view(arr, &specs)));
+
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
+// Every dimension takes three arguments: either an index array followed by two ignored
+// values or the start, end and step of a slice
+ryna_ffi_function!(advanced_index_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let num_dims = args[1].as_i64() as usize;
+
+    rynd_dims_check(arr, Some(num_dims), None);
+
+    let mut slices = vec![];
+    let mut indices = vec![];
+
+    for (d_idx, s) in args[2..2 + num_dims * 3].chunks_exact(3).enumerate() {
+        match s[0] {
+            FFIValue::Pointer(p) => {
+                let idx = ptr_to_ref(p);
+                rynd_index_array_check(idx);
+
+                slices.push(Slice::new(0, None, 1));
+                indices.push((d_idx, idx));
+            },
+
+            _ => slices.push(ffi_slice(arr, Some(s[0].as_i64() as isize), Some(s[1].as_i64() as isize), s[2].as_i64() as isize, d_idx))
+        }
+    }
+
+    let res = Box::new(arr.slice(slices).advanced_index(&mut indices));
+
+    unsafe { *out = register_and_leak(res).into(); }
+});
+
 ryna_ffi_function!(permute_axes(args, out) {
     let arr_ptr = args[0].as_ptr();
     let num_dims = args[1].as_i64() as usize;
@@ -312,74 +736,1066 @@ ryna_ffi_function!(permute_axes(args, out) {
     unsafe { *out = view_ptr.into(); }
 });
 
-ryna_ffi_function!(matmul(args, out) {
-    let a_ptr = args[0].as_ptr();
-    let b_ptr = args[1].as_ptr();
-    let a = ptr_to_ref(a_ptr);
-    let b = ptr_to_ref(b_ptr);
+ryna_ffi_function!(squeeze_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let a = ptr_to_ref(arr_ptr);
 
-    rynd_matmul_check(a, b);
+    let axes = a.shape().iter().enumerate()
+                .filter(|(_, l)| **l == 1)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
 
-    let array = Box::new(a.matmul(b));
+    let res = Box::new(a.squeeze(&axes));
+    let view_ptr = register_and_leak(res);
 
-    unsafe { *out = register_and_leak(array).into(); }
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
 });
 
-ryna_ffi_function!(rand_array(args, out) {
-    let num_dims = args[0].as_i64() as usize;
-    let shape = args[1..1 + num_dims].into_iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+ryna_ffi_function!(squeeze_axis_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let a = ptr_to_ref(arr_ptr);
+    let mut dim = args[1].as_i64();
 
-    let array = Box::new(NDArrayOwned::rand(shape).into());
+    rynd_normalize_dim(a, &mut dim);
 
-    unsafe { *out = register_and_leak(array).into(); }
-});
+    if a.shape()[dim as usize] != 1 {
+        rynd_error!("Unable to squeeze dimension {} of size {} (shape is {:?})", dim, a.shape()[dim as usize], a.shape());
+    }
 
-ryna_ffi_function!(normal_array(args, out) {
-    let mean = args[0].as_f64();
-    let std = args[1].as_f64();
-    let num_dims = args[2].as_i64() as usize;
-    let shape = args[3..3 + num_dims].into_iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+    let res = Box::new(a.squeeze(&[dim as usize]));
+    let view_ptr = register_and_leak(res);
 
-    let array = Box::new(NDArrayOwned::normal(mean, std, shape).into());
+    register_view(arr_ptr, view_ptr);
 
-    unsafe { *out = register_and_leak(array).into(); }
+    unsafe { *out = view_ptr.into(); }
 });
 
-ryna_ffi_function!(stack_arrays(args, out) {
-    let a_ptr = args[0].as_ptr();
-    let b_ptr = args[1].as_ptr();
-    let dim = args[2].as_i64();
-    let a = ptr_to_ref(a_ptr);
-    let b = ptr_to_ref(b_ptr);
+ryna_ffi_function!(expand_dims_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let a = ptr_to_ref(arr_ptr);
+    let mut dim = args[1].as_i64();
 
-    if dim < 0 {
-        rynd_error!("Negative dimensions are not allowed in stack function ({} given)", dim);
-    }
+    rynd_normalize_new_dim(a, &mut dim);
 
-    if dim as usize > a.shape().len() || dim as usize > b.shape().len() {
-        rynd_error!("Dimension {} out of range for stack function", dim);
-    }
+    let res = Box::new(a.expand_dims(dim as usize));
+    let view_ptr = register_and_leak(res);
 
-    let array = Box::new(a.stack(b, dim as usize));
+    register_view(arr_ptr, view_ptr);
 
-    unsafe { *out = register_and_leak(array).into(); }
+    unsafe { *out = view_ptr.into(); }
 });
 
-ryna_ffi_function!(concat_arrays(args, out) {
-    let a_ptr = args[0].as_ptr();
-    let b_ptr = args[1].as_ptr();
-    let mut dim = args[2].as_i64();
-    let a = ptr_to_ref(a_ptr);
-    let b = ptr_to_ref(b_ptr);
+ryna_ffi_function!(swap_axes_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let a = ptr_to_ref(arr_ptr);
+    let mut dim_a = args[1].as_i64();
+    let mut dim_b = args[2].as_i64();
 
-    rynd_normalize_dim(a, &mut dim);
+    rynd_normalize_dim(a, &mut dim_a);
+    rynd_normalize_dim(a, &mut dim_b);
+
+    let res = Box::new(a.swap_axes(dim_a as usize, dim_b as usize));
+    let view_ptr = register_and_leak(res);
+
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
+ryna_ffi_function!(move_axis_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let a = ptr_to_ref(arr_ptr);
+    let mut source = args[1].as_i64();
+    let mut destination = args[2].as_i64();
+
+    rynd_normalize_dim(a, &mut source);
+    rynd_normalize_dim(a, &mut destination);
+
+    let res = Box::new(a.move_axis(source as usize, destination as usize));
+    let view_ptr = register_and_leak(res);
+
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
+ryna_ffi_function!(matmul(args, out) {
+    let a_ptr = args[0].as_ptr();
+    let b_ptr = args[1].as_ptr();
+    let a = ptr_to_ref(a_ptr);
+    let b = ptr_to_ref(b_ptr);
+
+    rynd_matmul_check(a, b);
+
+    let array = Box::new(a.matmul(b));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+// Linear algebra
+ryna_ffi_function!(solve_arrays(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let b = ptr_to_ref(args[1].as_ptr());
+
+    rynd_square_check(a);
+    rynd_solve_check(a, b);
+
+    let array = Box::new(a.solve(b));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(inv_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_square_check(a);
+
+    let array = Box::new(a.inv());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(det_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_square_check(a);
+
+    unsafe { *out = a.det().into(); }
+});
+
+ryna_ffi_function!(slogdet_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_square_check(a);
+
+    let array = Box::new(a.slogdet());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(matrix_rank_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let tol = args[1].as_f64();
+
+    rynd_dims_check(a, Some(2), Some(2));
+
+    let tol = if tol < 0.0 { None } else { Some(tol) };
+
+    unsafe { *out = a.matrix_rank(tol).into(); }
+});
+
+ryna_ffi_function!(matrix_power_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let n = args[1].as_i64();
+
+    rynd_square_check(a);
+
+    let array = Box::new(a.matrix_power(n));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(pinv_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let rcond = args[1].as_f64();
+
+    rynd_dims_check(a, Some(2), Some(2));
+
+    let rcond = if rcond < 0.0 { None } else { Some(rcond) };
+    let array = Box::new(a.pinv(rcond));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(lstsq_arrays(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let b = ptr_to_ref(args[1].as_ptr());
+    let rcond = args[2].as_f64();
+
+    rynd_dims_check(a, Some(2), Some(2));
+    rynd_solve_check(a, b);
+
+    let rcond = if rcond < 0.0 { None } else { Some(rcond) };
+
+    unsafe { *out = register_and_leak_list(a.lstsq(b, rcond)).into(); }
+});
+
+ryna_ffi_function!(norm_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let p = args[1].as_f64();
+
+    unsafe { *out = a.norm(p).into(); }
+});
+
+ryna_ffi_function!(axis_norm_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let p = args[1].as_f64();
+    let mut dim = args[2].as_i64();
+
+    rynd_normalize_dim(a, &mut dim);
+
+    let array = Box::new(a.axis_norm(p, dim as usize));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(matrix_norm_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let kind = args[1].as_i64() as usize;
+
+    rynd_dims_check(a, Some(2), Some(2));
+
+    match kind.try_into() {
+        Ok(norm) => unsafe { *out = a.matrix_norm(norm).into(); },
+        Err(_) => rynd_error!("Invalid matrix norm {kind}"),
+    };
+});
+
+ryna_ffi_function!(lu_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_dims_check(a, Some(2), Some(2));
+
+    unsafe { *out = register_and_leak_list(a.lu()).into(); }
+});
+
+ryna_ffi_function!(qr_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let complete = args[1].as_i64() != 0;
+
+    rynd_dims_check(a, Some(2), Some(2));
+
+    unsafe { *out = register_and_leak_list(a.qr(complete)).into(); }
+});
+
+ryna_ffi_function!(cholesky_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_square_check(a);
+
+    let array = Box::new(a.cholesky());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(svd_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+    let full = args[1].as_i64() != 0;
+
+    rynd_dims_check(a, Some(2), Some(2));
+
+    unsafe { *out = register_and_leak_list(a.svd(full)).into(); }
+});
+
+ryna_ffi_function!(eigh_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_square_check(a);
+
+    unsafe { *out = register_and_leak_list(a.eigh()).into(); }
+});
+
+ryna_ffi_function!(eig_array(args, out) {
+    let a = ptr_to_ref(args[0].as_ptr());
+
+    rynd_square_check(a);
+
+    unsafe { *out = register_and_leak_list(a.eig()).into(); }
+});
+
+// Random generators
+ryna_ffi_function!(create_generator_seeded(args, out) {
+    let seed = args[0].as_i64() as u64;
+
+    unsafe { *out = create_generator(Some(seed)).into(); }
+});
+
+ryna_ffi_function!(create_generator_entropy(_args, out) {
+    unsafe { *out = create_generator(None).into(); }
+});
+
+ryna_ffi_function!(seed_generator_ptr(args, _out) {
+    let generator = generator_ptr_to_ref(args[0].as_ptr());
+
+    seed_generator(generator, args[1].as_i64() as u64);
+});
+
+ryna_ffi_function!(advance_generator_ptr(args, _out) {
+    let generator = generator_ptr_to_ref(args[0].as_ptr());
+    let n = args[1].as_i64();
+
+    if n < 0 {
+        rynd_error!("Unable to advance a generator by a negative amount ({n})");
+    }
+
+    advance_generator(generator, n as u64);
+});
+
+ryna_ffi_function!(free_generator(args, _out) {
+    free_generator_ptr(args[0].as_ptr());
+});
+
+ryna_ffi_function!(rand_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let num_dims = args[1].as_i64() as usize;
+    let shape = args[2..2 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let array = Box::new(NDArrayOwned::rand(&mut rng, shape).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(normal_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let mean = args[1].as_f64();
+    let std = args[2].as_f64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    if std < 0.0 || !std.is_finite() {
+        rynd_error!("Invalid standard deviation for normal distribution ({std})");
+    }
+
+    let array = Box::new(NDArrayOwned::normal(&mut rng, mean, std, shape).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(uniform_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let low = args[1].as_f64();
+    let high = args[2].as_f64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("uniform", Uniform::new(low, high));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(randint_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let low = args[1].as_i64();
+    let high = args[2].as_i64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("uniform integer", Uniform::new(low, high));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(bernoulli_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let p = args[1].as_f64();
+    let num_dims = args[2].as_i64() as usize;
+    let shape = args[3..3 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("Bernoulli", Bernoulli::new(p));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(binomial_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let n = args[1].as_i64();
+    let p = args[2].as_f64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    if n < 0 {
+        rynd_error!("Invalid parameters for binomial distribution (n = {n} is negative)");
+    }
+
+    let dist = distribution("binomial", Binomial::new(n as u64, p));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng) as i64).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(poisson_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let lambda = args[1].as_f64();
+    let num_dims = args[2].as_i64() as usize;
+    let shape = args[3..3 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("Poisson", Poisson::new(lambda));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng) as i64).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(exponential_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let scale = args[1].as_f64();
+    let num_dims = args[2].as_i64() as usize;
+    let shape = args[3..3 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("exponential", Exp::new(1.0 / scale));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(gamma_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let k = args[1].as_f64();
+    let scale = args[2].as_f64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("gamma", Gamma::new(k, scale));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(beta_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let a = args[1].as_f64();
+    let b = args[2].as_f64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("beta", Beta::new(a, b));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(lognormal_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let mean = args[1].as_f64();
+    let sigma = args[2].as_f64();
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let dist = distribution("log-normal", LogNormal::new(mean, sigma));
+    let array = Box::new(NDArrayOwned::from_fn(shape, || dist.sample(&mut rng)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(multivariate_normal_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let mean = ptr_to_ref(args[1].as_ptr());
+    let cov = ptr_to_ref(args[2].as_ptr());
+    let num_dims = args[3].as_i64() as usize;
+    let shape = args[4..4 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    rynd_dims_check(mean, Some(1), Some(1));
+    rynd_square_check(cov);
+
+    let mean = mean.view().to_f64().into_dimensionality::<Ix1>().unwrap();
+    let cov = cov.view().to_f64().into_dimensionality::<Ix2>().unwrap();
+
+    let array = Box::new(NDArrayOwned::from(multivariate_normal(&mut rng, &mean.view(), &cov.view(), shape)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(dirichlet_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let alpha = ptr_to_ref(args[1].as_ptr());
+    let num_dims = args[2].as_i64() as usize;
+    let shape = args[3..3 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    rynd_dims_check(alpha, Some(1), Some(1));
+
+    let alpha = alpha.view().to_f64().into_dimensionality::<Ix1>().unwrap();
+
+    let array = Box::new(NDArrayOwned::from(dirichlet(&mut rng, &alpha.view(), shape)).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(shuffle_array(args, _out) {
+    rynd_writable_check(args[1].as_ptr());
+
+    let mut rng = rng_from_ffi(&args[0]);
+    let arr = ptr_to_ref(args[1].as_ptr());
+
+    rynd_dims_check(arr, Some(1), None);
+
+    arr.shuffle(&mut rng);
+});
+
+ryna_ffi_function!(permutation_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let n = args[1].as_i64();
+
+    if n < 0 {
+        rynd_error!("Permutation length must be non-negative ({} given)", n);
+    }
+
+    let n = n as usize;
+
+    let array = Box::new(NDArrayOwned::from(Array1::from_vec(permutation(&mut rng, n)).into_dyn()).into());
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+// Probabilities are optional, a 0 is received instead of an array when there are none
+ryna_ffi_function!(choice_array(args, out) {
+    let mut rng = rng_from_ffi(&args[0]);
+    let arr = ptr_to_ref(args[1].as_ptr());
+    let size = args[2].as_i64();
+    let replace = args[3].as_bool();
+
+    if size < 0 {
+        rynd_error!("Number of choices must be non-negative ({} given)", size);
+    }
+
+    let size = size as usize;
+
+    rynd_dims_check(arr, Some(1), None);
+
+    let p = match args[4] {
+        FFIValue::Pointer(p) => {
+            let probs = ptr_to_ref(p);
+            rynd_dims_check(probs, Some(1), Some(1));
+
+            Some(probs.view().to_f64().iter().cloned().collect::<Vec<_>>())
+        },
+        _ => None
+    };
+
+    let indices = choice_indices(&mut rng, arr.shape()[0], size, replace, p.as_deref());
+
+    let array = Box::new(arr.select(0, &indices));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(stack_arrays(args, out) {
+    let a_ptr = args[0].as_ptr();
+    let b_ptr = args[1].as_ptr();
+    let dim = args[2].as_i64();
+    let a = ptr_to_ref(a_ptr);
+    let b = ptr_to_ref(b_ptr);
+
+    if dim < 0 {
+        rynd_error!("Negative dimensions are not allowed in stack function ({} given)", dim);
+    }
+
+    if dim as usize > a.shape().len() || dim as usize > b.shape().len() {
+        rynd_error!("Dimension {} out of range for stack function", dim);
+    }
+
+    let array = Box::new(a.stack(b, dim as usize));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(concat_arrays(args, out) {
+    let a_ptr = args[0].as_ptr();
+    let b_ptr = args[1].as_ptr();
+    let mut dim = args[2].as_i64();
+    let a = ptr_to_ref(a_ptr);
+    let b = ptr_to_ref(b_ptr);
+
+    rynd_normalize_dim(a, &mut dim);
     rynd_normalize_dim(b, &mut dim);
 
-    let array = Box::new(a.concat(b, dim as usize));
+    let array = Box::new(a.concat(b, dim as usize));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+fn concatenate_arrays(arrays: &mut [&mut NDArray], mut dim: i64) -> NDArray {
+    if let Some(a) = arrays.first() {
+        rynd_dims_check(a, Some(1), None);
+        rynd_normalize_dim(a, &mut dim);
+    }
+
+    NDArray::concatenate(arrays, dim as usize)
+}
+
+fn stack_all_arrays(arrays: &mut [&mut NDArray], mut dim: i64) -> NDArray {
+    if let Some(a) = arrays.first() {
+        rynd_normalize_new_dim(a, &mut dim);
+    }
+
+    NDArray::stack_all(arrays, dim as usize)
+}
+
+fn hstack_arrays(arrays: &mut [&mut NDArray], _dim: i64) -> NDArray {
+    arrays.iter().for_each(|a| rynd_dims_check(a, Some(1), None));
+
+    NDArray::hstack(arrays)
+}
+
+fn vstack_arrays(arrays: &mut [&mut NDArray], _dim: i64) -> NDArray {
+    arrays.iter().for_each(|a| rynd_dims_check(a, Some(1), None));
+
+    NDArray::vstack(arrays)
+}
+
+fn dstack_arrays(arrays: &mut [&mut NDArray], _dim: i64) -> NDArray {
+    arrays.iter().for_each(|a| rynd_dims_check(a, Some(1), None));
+
+    NDArray::dstack(arrays)
+}
+
+// Joining functions receive a list handle. The ones with fixed arity overloads can also receive the
+// arrays as arguments (dimension, count and pointers)
+macro_rules! join_rynd_fn {
+    ($list_name: ident, $name: ident) => {
+        ryna_ffi_function!($list_name(args, out) {
+            let list = list_ptr_to_ref(args[0].as_ptr());
+            let dim = args[1].as_i64();
+            let mut arrays = list.iter().map(|i| ptr_to_ref(*i)).collect::<Vec<_>>();
+
+            let array = Box::new($name(&mut arrays, dim));
+
+            unsafe { *out = register_and_leak(array).into(); }
+        });
+    };
+
+    ($public_name: ident, $list_name: ident, $name: ident) => {
+        ryna_ffi_function!($public_name(args, out) {
+            let dim = args[0].as_i64();
+            let count = args[1].as_i64() as usize;
+            let mut arrays = args[2..2 + count].iter().map(|i| ptr_to_ref(i.as_ptr())).collect::<Vec<_>>();
+
+            let array = Box::new($name(&mut arrays, dim));
+
+            unsafe { *out = register_and_leak(array).into(); }
+        });
+
+        join_rynd_fn!($list_name, $name);
+    };
+}
+
+join_rynd_fn!(concatenate_list, concatenate_arrays);
+join_rynd_fn!(stack_list, stack_all_arrays);
+join_rynd_fn!(hstack_many, hstack_list, hstack_arrays);
+join_rynd_fn!(vstack_many, vstack_list, vstack_arrays);
+join_rynd_fn!(dstack_many, dstack_list, dstack_arrays);
+
+// Sections are returned as a list of views of the original array
+fn split_views(arr_ptr: *const c_void, bounds: &[(usize, usize)], dim: usize) -> *const c_void {
+    let arr = ptr_to_ref(arr_ptr);
+
+    let views = bounds.iter()
+                      .map(|(s, e)| arr.slice_axis(dim, Slice::from(*s..*e)))
+                      .collect::<Vec<_>>();
+
+    let list = register_and_leak_list(views);
+
+    for view_ptr in list_ptr_to_ref(list).iter() {
+        register_view(arr_ptr, *view_ptr);
+    }
+
+    list
+}
+
+fn section_bounds(len: usize, sections: i64, exact: bool) -> Vec<(usize, usize)> {
+    if sections <= 0 {
+        rynd_error!("Number of sections must be positive ({} given)", sections);
+    }
+
+    let sections = sections as usize;
+
+    if exact && !len.is_multiple_of(sections) {
+        rynd_error!("Unable to split a dimension of size {} into {} equal sections", len, sections);
+    }
+
+    let (size, extra) = (len / sections, len % sections);
+    let mut start = 0;
+
+    (0..sections).map(|i| {
+        let end = start + size + (i < extra) as usize;
+        let res = (start, end);
+        start = end;
+
+        res
+    }).collect()
+}
+
+// Split points behave like slice bounds, so out of range or decreasing indices produce empty sections
+fn index_bounds(len: usize, indices: &[i64]) -> Vec<(usize, usize)> {
+    let clamp = |i: i64| (if i < 0 { i + len as i64 } else { i }).clamp(0, len as i64) as usize;
+
+    let mut points = vec![0];
+    points.extend(indices.iter().map(|i| clamp(*i)));
+    points.push(len);
+
+    points.windows(2).map(|w| (w[0], w[1].max(w[0]))).collect()
+}
+
+// Split modes: 0 splits along the given dimension, 1 and 2 behave like hsplit and vsplit
+fn split_dim(arr: &NDArray, mode: i64, dim: i64) -> i64 {
+    match mode {
+        1 => if arr.shape().len() == 1 { 0 } else { 1 },
+
+        2 => {
+            rynd_dims_check(arr, Some(2), None);
+            0
+        },
+
+        _ => dim
+    }
+}
+
+ryna_ffi_function!(split_sections_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let sections = args[1].as_i64();
+    let exact = args[4].as_bool();
+
+    rynd_dims_check(arr, Some(1), None);
+
+    let mut dim = split_dim(arr, args[3].as_i64(), args[2].as_i64());
+
+    rynd_normalize_dim(arr, &mut dim);
+
+    let bounds = section_bounds(arr.shape()[dim as usize], sections, exact);
+
+    unsafe { *out = split_views(arr_ptr, &bounds, dim as usize).into(); }
+});
+
+ryna_ffi_function!(split_indices_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let indices = ptr_to_ref(args[1].as_ptr());
+
+    rynd_dims_check(arr, Some(1), None);
+    rynd_dims_check(indices, Some(1), Some(1));
+    rynd_index_array_check(indices);
+
+    let mut dim = split_dim(arr, args[3].as_i64(), args[2].as_i64());
+
+    rynd_normalize_dim(arr, &mut dim);
+
+    let indices = indices.view().to_i64().iter().cloned().collect::<Vec<_>>();
+    let bounds = index_bounds(arr.shape()[dim as usize], &indices);
+
+    unsafe { *out = split_views(arr_ptr, &bounds, dim as usize).into(); }
+});
+
+// Indices are either a single Int or an Int array, and negative ones count from the end
+fn index_list(value: &FFIValue, len: usize, allow_end: bool) -> Vec<usize> {
+    let indices = match value {
+        FFIValue::Int(i) => vec![*i],
+        FFIValue::Pointer(p) => {
+            let indices = ptr_to_ref(*p);
+
+            rynd_dims_check(indices, Some(1), Some(1));
+            rynd_index_array_check(indices);
+
+            indices.view().to_i64().iter().cloned().collect()
+        },
+        _ => unreachable!()
+    };
+
+    indices.into_iter().map(|i| rynd_normalize_index(i, len, allow_end)).collect()
+}
+
+// Scalars are turned into 0-dimensional arrays of the given type
+fn array_or_scalar(value: &FFIValue, tp: NDArrayType) -> NDArray {
+    match value {
+        FFIValue::Pointer(p) => NDArray::from(ptr_to_ref(*p).view()),
+        FFIValue::Int(v) => NDArrayOwned::full_i64(tp, vec![], *v).into(),
+        FFIValue::Float(v) => NDArrayOwned::full_f64(tp, vec![], *v).into(),
+    }
+}
+
+macro_rules! scatter_rynd_fn {
+    ($function: ident, $op: expr) => {
+        ryna_ffi_function!($function(args, _out) {
+            rynd_writable_check(args[0].as_ptr());
+
+            let arr = ptr_to_ref(args[0].as_ptr());
+            let num_idx = args[2].as_i64() as usize;
+
+            rynd_dims_check(arr, Some(num_idx), None);
+
+            let mut values = array_or_scalar(&args[1], arr.dtype());
+            let mut indices = args[3..3 + num_idx].iter().map(|i| ptr_to_ref(i.as_ptr())).collect::<Vec<_>>();
+
+            indices.iter().for_each(|i| rynd_index_array_check(i));
+
+            arr.scatter(&mut indices, &mut values, $op);
+        });
+    };
+}
+
+scatter_rynd_fn!(put_array, ScatterOp::Assign);
+scatter_rynd_fn!(add_at_array, ScatterOp::Add);
+scatter_rynd_fn!(max_at_array, ScatterOp::Max);
+scatter_rynd_fn!(min_at_array, ScatterOp::Min);
+
+// Scalars keep their own type, so they take part in type promotion
+fn promoted_array_or_scalar(value: &FFIValue) -> NDArray {
+    match value {
+        FFIValue::Float(_) => array_or_scalar(value, NDArrayType::Float),
+        _ => array_or_scalar(value, NDArrayType::Int),
+    }
+}
+
+ryna_ffi_function!(where_arrays(args, out) {
+    let cond = ptr_to_ref(args[0].as_ptr());
+    let mut x = promoted_array_or_scalar(&args[1]);
+    let mut y = promoted_array_or_scalar(&args[2]);
+
+    rynd_condition_check(cond);
+
+    let array = Box::new(NDArray::where_cond(cond, &mut x, &mut y));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(select_list(args, out) {
+    let mut conds = list_ptr_to_ref(args[0].as_ptr()).iter().map(|i| ptr_to_ref(*i)).collect::<Vec<_>>();
+    let mut choices = list_ptr_to_ref(args[1].as_ptr()).iter().map(|i| ptr_to_ref(*i)).collect::<Vec<_>>();
+    let mut default = promoted_array_or_scalar(&args[2]);
+
+    conds.iter().for_each(|c| rynd_condition_check(c));
+
+    let array = Box::new(NDArray::select_cond(&mut conds, &mut choices, &mut default));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(choose_list(args, out) {
+    let indices = ptr_to_ref(args[0].as_ptr());
+    let mut choices = list_ptr_to_ref(args[1].as_ptr()).iter().map(|i| ptr_to_ref(*i)).collect::<Vec<_>>();
+    let mode = args[2].as_i64() as usize;
+
+    rynd_index_array_check(indices);
+
+    let mode: TakeMode = match mode.try_into() {
+        Ok(m) => m,
+        Err(_) => rynd_error!("Invalid take mode {mode}"),
+    };
+
+    let array = Box::new(NDArray::choose(indices, &mut choices, mode));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+// Normalizes the dimension of functions that flatten the array when no dimension is given
+fn optional_dim(arr: &NDArray, dim: i64, flatten: bool) -> Option<usize> {
+    if flatten {
+        return None;
+    }
+
+    let mut dim = dim;
+    rynd_normalize_dim(arr, &mut dim);
+
+    Some(dim as usize)
+}
+
+fn flat_len(arr: &NDArray, dim: Option<usize>) -> usize {
+    match dim {
+        Some(d) => arr.shape()[d],
+        None => arr.len(),
+    }
+}
+
+ryna_ffi_function!(delete_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let dim = optional_dim(arr, args[2].as_i64(), args[3].as_bool());
+    let indices = index_list(&args[1], flat_len(arr, dim), false);
+
+    let array = Box::new(arr.delete(&indices, dim));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+// Values can be an array or a scalar
+ryna_ffi_function!(insert_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let dim = optional_dim(arr, args[3].as_i64(), args[4].as_bool());
+    let indices = index_list(&args[1], flat_len(arr, dim), true);
+
+    let mut values = array_or_scalar(&args[2], arr.dtype());
+
+    let array = match args[1] {
+        FFIValue::Int(_) => Box::new(arr.insert_at(indices[0], &mut values, dim)),
+        _ => Box::new(arr.insert(&indices, &mut values, dim)),
+    };
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(append_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let values = ptr_to_ref(args[1].as_ptr());
+    let dim = optional_dim(arr, args[2].as_i64(), args[3].as_bool());
+
+    let array = Box::new(arr.append(values, dim));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+// A single Int index removes the dimension, while an array replaces it with its own shape
+ryna_ffi_function!(take_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let dim = optional_dim(arr, args[2].as_i64(), args[3].as_bool());
+    let mode = args[4].as_i64() as usize;
+    let len = flat_len(arr, dim);
+
+    let mode: TakeMode = match mode.try_into() {
+        Ok(m) => m,
+        Err(_) => rynd_error!("Invalid take mode {mode}"),
+    };
+
+    let (indices, idx_shape) = match args[1] {
+        FFIValue::Pointer(p) => {
+            let idx = ptr_to_ref(p);
+            rynd_index_array_check(idx);
+
+            (idx.view().to_i64().iter().map(|i| mode.normalize(*i, len)).collect::<Vec<_>>(), idx.shape().to_vec())
+        },
+
+        _ => (vec![mode.normalize(args[1].as_i64(), len)], vec![])
+    };
+
+    let array = Box::new(arr.take(&indices, &idx_shape, dim));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(take_along_axis_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let indices = ptr_to_ref(args[1].as_ptr());
+    let dim = optional_dim(arr, args[2].as_i64(), args[3].as_bool());
+    let num_dims = if dim.is_some() { arr.shape().len() } else { 1 };
+
+    rynd_index_array_check(indices);
+    rynd_dims_check(indices, Some(num_dims), Some(num_dims));
+
+    let array = Box::new(arr.take_along_axis(indices, dim));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(put_along_axis_array(args, _out) {
+    rynd_writable_check(args[0].as_ptr());
+
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let indices = ptr_to_ref(args[1].as_ptr());
+    let mut values = array_or_scalar(&args[2], arr.dtype());
+    let mut dim = args[3].as_i64();
+
+    rynd_normalize_dim(arr, &mut dim);
+    rynd_index_array_check(indices);
+    rynd_dims_check(indices, Some(arr.shape().len()), Some(arr.shape().len()));
+
+    arr.put_along_axis(indices, &mut values, dim as usize);
+});
+
+// Widths are given as (before, after) pairs, either one for every dimension or a single one for all of them
+ryna_ffi_function!(pad_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let mode = args[1].as_i64() as usize;
+    let constant = ffi_number(&args[2]);
+    let num_widths = args[3].as_i64() as usize;
+
+    let widths = args[4..4 + num_widths * 2].iter()
+        .map(|i| i.as_i64())
+        .collect::<Vec<_>>();
+
+    if let Some(w) = widths.iter().find(|w| **w < 0) {
+        rynd_error!("Pad widths cannot be negative ({} given)", w);
+    }
+
+    let mut widths = widths.chunks_exact(2).map(|w| (w[0] as usize, w[1] as usize)).collect::<Vec<_>>();
+    let num_dims = arr.shape().len();
+
+    if widths.len() == 1 {
+        widths = vec![widths[0]; num_dims];
+    }
+
+    if widths.len() != num_dims {
+        rynd_error!("Expected {} pad widths for an array of shape {:?} (got {})", num_dims, arr.shape(), widths.len());
+    }
+
+    let mode: PadMode = match mode.try_into() {
+        Ok(m) => m,
+        Err(_) => rynd_error!("Invalid pad mode {mode}"),
+    };
+
+    let array = Box::new(arr.pad(&widths, mode, constant));
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+// Repetitions are either a single Int or an Int array with one count per element
+ryna_ffi_function!(repeat_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let mut dim = args[2].as_i64();
+    let flatten = args[3].as_bool();
+
+    let counts = match args[1] {
+        FFIValue::Int(n) => vec![n],
+        FFIValue::Pointer(p) => {
+            let counts = ptr_to_ref(p);
+
+            rynd_dims_check(counts, Some(1), Some(1));
+            rynd_index_array_check(counts);
+
+            counts.view().to_i64().iter().cloned().collect()
+        },
+        _ => unreachable!()
+    };
+
+    if let Some(c) = counts.iter().find(|c| **c < 0) {
+        rynd_error!("Repetition counts cannot be negative ({} given)", c);
+    }
+
+    let counts = counts.into_iter().map(|c| c as usize).collect::<Vec<_>>();
+
+    let array = Box::new(if flatten {
+        arr.repeat(&counts, None)
+
+    } else {
+        rynd_normalize_dim(arr, &mut dim);
+        arr.repeat(&counts, Some(dim as usize))
+    });
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
+ryna_ffi_function!(tile_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let num_reps = args[1].as_i64() as usize;
+    let reps = args[2..2 + num_reps].iter().map(|i| i.as_i64()).collect::<Vec<_>>();
+
+    if let Some(r) = reps.iter().find(|r| **r < 0) {
+        rynd_error!("Tile repetitions cannot be negative ({} given)", r);
+    }
+
+    let reps = reps.into_iter().map(|r| r as usize).collect::<Vec<_>>();
+
+    let array = Box::new(arr.tile(&reps));
 
     unsafe { *out = register_and_leak(array).into(); }
 });
 
+// The result shares memory with the original array and is marked as read-only
+ryna_ffi_function!(broadcast_to_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let num_dims = args[1].as_i64() as usize;
+    let shape = args[2..2 + num_dims].iter().map(|i| i.as_i64() as usize).collect::<Vec<_>>();
+
+    let res = Box::new(arr.broadcast_to(&shape));
+    let view_ptr = register_and_leak(res);
+
+    register_view(arr_ptr, view_ptr);
+    set_readonly(view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
 // Unary functions
 macro_rules! unary_rynd_fn {
     ($public_name: ident, $name: ident) => {
@@ -412,6 +1828,14 @@ unary_rynd_fn!(log10_array, log10);
 unary_rynd_fn!(cosh_array, cosh);
 unary_rynd_fn!(sinh_array, sinh);
 unary_rynd_fn!(tanh_array, tanh);
+unary_rynd_fn!(abs_array, abs);
+unary_rynd_fn!(sign_array, sign);
+unary_rynd_fn!(square_array, square);
+unary_rynd_fn!(exp2_array, exp2);
+unary_rynd_fn!(expm1_array, expm1);
+unary_rynd_fn!(log1p_array, log1p);
+unary_rynd_fn!(cbrt_array, cbrt);
+unary_rynd_fn!(reciprocal_array, reciprocal);
 
 ryna_ffi_function!(clip_array(args, out) {
     let arr = ptr_to_ref(args[0].as_ptr());
@@ -452,6 +1876,8 @@ axis_rynd_fn!(axis_argmin_array, axis_argmin);
 axis_rynd_fn!(axis_argmax_array, axis_argmax);
 
 ryna_ffi_function!(axis_sort_array(args, _out) {
+    rynd_writable_check(args[0].as_ptr());
+
     let arr = ptr_to_ref(args[0].as_ptr());
     let mut dim = args[1].as_i64();
     
@@ -475,6 +1901,77 @@ ryna_ffi_function!(axis_reverse_array(args, out) {
     unsafe { *out = view_ptr.into(); }
 });
 
+// Every dimension is flipped when no dimensions are given
+ryna_ffi_function!(flip_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let num_axes = args[1].as_i64() as usize;
+
+    let mut axes = args[2..2 + num_axes].iter().map(|i| {
+        let mut dim = i.as_i64();
+        rynd_normalize_dim(arr, &mut dim);
+
+        dim as usize
+    }).collect::<Vec<_>>();
+
+    if num_axes == 0 {
+        axes = (0..arr.shape().len()).collect();
+    }
+
+    axes.sort();
+
+    if axes.windows(2).any(|w| w[0] == w[1]) {
+        rynd_error!("Repeated dimension in flip function");
+    }
+
+    let res = Box::new(arr.flip(&axes));
+    let view_ptr = register_and_leak(res);
+
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
+ryna_ffi_function!(rot90_array(args, out) {
+    let arr_ptr = args[0].as_ptr();
+    let arr = ptr_to_ref(arr_ptr);
+    let k = args[1].as_i64();
+    let mut dim_a = args[2].as_i64();
+    let mut dim_b = args[3].as_i64();
+
+    rynd_dims_check(arr, Some(2), None);
+    rynd_normalize_dim(arr, &mut dim_a);
+    rynd_normalize_dim(arr, &mut dim_b);
+
+    if dim_a == dim_b {
+        rynd_error!("Rotation dimensions must be different ({} given twice)", dim_a);
+    }
+
+    let res = Box::new(arr.rot90(k, dim_a as usize, dim_b as usize));
+    let view_ptr = register_and_leak(res);
+
+    register_view(arr_ptr, view_ptr);
+
+    unsafe { *out = view_ptr.into(); }
+});
+
+ryna_ffi_function!(roll_array(args, out) {
+    let arr = ptr_to_ref(args[0].as_ptr());
+    let shift = args[1].as_i64();
+    let mut dim = args[2].as_i64();
+    let flatten = args[3].as_bool();
+
+    let array = Box::new(if flatten {
+        arr.roll(shift, None)
+
+    } else {
+        rynd_normalize_dim(arr, &mut dim);
+        arr.roll(shift, Some(dim as usize))
+    });
+
+    unsafe { *out = register_and_leak(array).into(); }
+});
+
 // Utility
 ryna_ffi_function!(cast_array(args, out) {
     let arr = ptr_to_ref(args[0].as_ptr());
@@ -490,4 +1987,43 @@ ryna_ffi_function!(print_array(args, _out) {
 
     print!("{}", arr);
     std::io::stdout().flush().unwrap();
-});
\ No newline at end of file
+});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_sections() {
+        assert_eq!(section_bounds(6, 3, true), [(0, 2), (2, 4), (4, 6)]);
+        assert_eq!(section_bounds(5, 3, false), [(0, 2), (2, 4), (4, 5)]);
+        assert_eq!(section_bounds(2, 4, false), [(0, 1), (1, 2), (2, 2), (2, 2)]);
+        assert_eq!(section_bounds(0, 2, true), [(0, 0), (0, 0)]);
+    }
+
+    #[test]
+    fn split_indices() {
+        assert_eq!(index_bounds(5, &[2, 4]), [(0, 2), (2, 4), (4, 5)]);
+        assert_eq!(index_bounds(5, &[-2]), [(0, 3), (3, 5)]);
+        assert_eq!(index_bounds(5, &[3, 1]), [(0, 3), (3, 3), (1, 5)]);
+        assert_eq!(index_bounds(5, &[10, -10]), [(0, 5), (5, 5), (0, 5)]);
+        assert_eq!(index_bounds(5, &[]), [(0, 5)]);
+    }
+
+    #[test]
+    fn index_views() {
+        let mut a: NDArray = NDArrayOwned::from(ndarray::ArrayD::from_shape_fn(vec![2, 3, 4], |i| (i[0] * 12 + i[1] * 4 + i[2]) as i64)).into();
+        let mut index = |specs: &[(i64, isize, isize, isize)]| {
+            let mut res = index_view(&mut a, specs);
+            (res.shape().to_vec(), res.view().to_i64().iter().cloned().collect::<Vec<_>>())
+        };
+
+        assert_eq!(index(&[(4, 1, 0, 0)]), (vec![3, 4], (12..24).collect()));
+        assert_eq!(index(&[(4, -1, 0, 0), (4, 0, 0, 0), (3, 0, 0, -1)]), (vec![4], vec![15, 14, 13, 12]));
+        assert_eq!(index(&[(6, 0, 0, 0), (4, 2, 0, 0)]), (vec![2, 3], vec![2, 6, 10, 14, 18, 22]));
+        assert_eq!(index(&[(5, 0, 0, 0), (0, 0, 1, 1)]), (vec![1, 1, 3, 4], (0..12).collect()));
+        assert_eq!(index(&[(4, 0, 0, 0), (5, 0, 0, 0), (1, 1, 0, 2)]), (vec![1, 1, 4], vec![4, 5, 6, 7]));
+        assert_eq!(index(&[(6, 0, 0, 0), (5, 0, 0, 0)]).0, vec![2, 3, 4, 1]);
+        assert_eq!(index(&[(2, 0, 1, 1), (6, 0, 0, 0), (0, 1, 3, 1)]), (vec![1, 3, 2], vec![1, 2, 5, 6, 9, 10]));
+    }
+}
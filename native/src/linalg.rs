@@ -0,0 +1,948 @@
+use ndarray::{s, Array1, Array2, ArrayD, ArrayView2, ArrayViewD, Axis, Ix2};
+use num_complex::Complex64;
+
+use crate::rynd_error;
+
+const MAX_JACOBI_SWEEPS: usize = 100;
+const MAX_QR_ITERATIONS: usize = 100;
+
+pub enum MatrixNorm {
+    Frobenius, Nuclear, Spectral
+}
+
+impl TryFrom<usize> for MatrixNorm {
+    type Error = ();
+
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MatrixNorm::Frobenius),
+            1 => Ok(MatrixNorm::Nuclear),
+            2 => Ok(MatrixNorm::Spectral),
+            _ => Err(())
+        }
+    }
+}
+
+pub struct LUFactors {
+    pub lu: Array2<f64>,
+    pub perm: Vec<usize>,
+    pub sign: f64,
+    pub singular: bool,
+}
+
+// LU factorization with partial pivoting (PA = LU, L has an implicit unit diagonal)
+pub fn lu_factor(a: &ArrayView2<f64>) -> LUFactors {
+    let (m, n) = a.dim();
+    let mut lu = a.to_owned();
+    let mut perm = (0..m).collect::<Vec<_>>();
+    let mut sign = 1.0;
+    let mut singular = false;
+
+    for k in 0..m.min(n) {
+        let p = (k..m).max_by(|&i, &j| lu[[i, k]].abs().total_cmp(&lu[[j, k]].abs())).unwrap();
+
+        if lu[[p, k]] == 0.0 {
+            singular = true;
+            continue;
+        }
+
+        if p != k {
+            for j in 0..n {
+                lu.swap([p, j], [k, j]);
+            }
+
+            perm.swap(p, k);
+            sign = -sign;
+        }
+
+        let pivot = lu[[k, k]];
+
+        for i in k + 1..m {
+            let f = lu[[i, k]] / pivot;
+            lu[[i, k]] = f;
+
+            for j in k + 1..n {
+                lu[[i, j]] -= f * lu[[k, j]];
+            }
+        }
+    }
+
+    LUFactors { lu, perm, sign, singular }
+}
+
+// Pivots below n * eps * max|a| can be produced by rounding errors alone, so systems with
+// such pivots are treated as singular instead of returning meaningless huge values
+fn is_numerically_singular(factors: &LUFactors, a: &ArrayView2<f64>) -> bool {
+    let max_abs = a.iter().fold(0.0f64, |m, v| m.max(v.abs()));
+    let tol = a.nrows().max(a.ncols()) as f64 * f64::EPSILON * max_abs;
+
+    factors.singular || factors.lu.diag().iter().any(|p| p.abs() <= tol)
+}
+
+pub fn lu(a: &ArrayView2<f64>) -> (Array2<f64>, Array2<f64>, Array2<f64>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let factors = lu_factor(a);
+
+    let mut p = Array2::<f64>::zeros((m, m));
+    let l = Array2::from_shape_fn((m, k), |(i, j)| if i > j { factors.lu[[i, j]] } else if i == j { 1.0 } else { 0.0 });
+    let u = Array2::from_shape_fn((k, n), |(i, j)| if i <= j { factors.lu[[i, j]] } else { 0.0 });
+
+    for (i, orig) in factors.perm.iter().enumerate() {
+        p[[*orig, i]] = 1.0;
+    }
+
+    (p, l, u)
+}
+
+fn lu_solve(factors: &LUFactors, b: &ArrayView2<f64>) -> Array2<f64> {
+    let lu = &factors.lu;
+    let n = lu.nrows();
+    let mut x = b.select(Axis(0), &factors.perm);
+
+    for mut col in x.columns_mut() {
+        for i in 0..n {
+            let mut acc = col[i];
+
+            for j in 0..i {
+                acc -= lu[[i, j]] * col[j];
+            }
+
+            col[i] = acc;
+        }
+
+        for i in (0..n).rev() {
+            let mut acc = col[i];
+
+            for j in i + 1..n {
+                acc -= lu[[i, j]] * col[j];
+            }
+
+            col[i] = acc / lu[[i, i]];
+        }
+    }
+
+    x
+}
+
+pub fn solve(a: &ArrayView2<f64>, b: &ArrayViewD<f64>) -> ArrayD<f64> {
+    let factors = lu_factor(a);
+
+    if is_numerically_singular(&factors, a) {
+        rynd_error!("Unable to solve linear system: matrix is singular");
+    }
+
+    if b.ndim() == 1 {
+        let b_col = b.view().insert_axis(Axis(1)).into_dimensionality::<Ix2>().unwrap();
+        lu_solve(&factors, &b_col).remove_axis(Axis(1)).into_dyn()
+
+    } else {
+        let b_mat = b.view().into_dimensionality::<Ix2>().unwrap();
+        lu_solve(&factors, &b_mat).into_dyn()
+    }
+}
+
+pub fn inv(a: &ArrayView2<f64>) -> Array2<f64> {
+    let factors = lu_factor(a);
+
+    if is_numerically_singular(&factors, a) {
+        rynd_error!("Unable to invert matrix: matrix is singular");
+    }
+
+    lu_solve(&factors, &Array2::eye(a.nrows()).view())
+}
+
+pub fn matrix_power(a: &ArrayView2<f64>, n: i64) -> Array2<f64> {
+    let mut base = if n < 0 { inv(a) } else { a.to_owned() };
+    let mut exp = n.unsigned_abs();
+    let mut res = Array2::<f64>::eye(a.nrows());
+
+    // Exponentiation by squaring
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = res.dot(&base);
+        }
+
+        exp >>= 1;
+
+        if exp > 0 {
+            base = base.dot(&base);
+        }
+    }
+
+    res
+}
+
+pub fn det(a: &ArrayView2<f64>) -> f64 {
+    let factors = lu_factor(a);
+
+    if factors.singular {
+        return 0.0;
+    }
+
+    factors.sign * factors.lu.diag().product()
+}
+
+pub fn slogdet(a: &ArrayView2<f64>) -> (f64, f64) {
+    let factors = lu_factor(a);
+
+    if factors.singular {
+        return (0.0, f64::NEG_INFINITY);
+    }
+
+    let diag = factors.lu.diag();
+    let sign = diag.iter().fold(factors.sign, |acc, d| acc * d.signum());
+    let logdet = diag.iter().map(|d| d.abs().ln()).sum();
+
+    (sign, logdet)
+}
+
+// Householder QR (A = QR), either reduced (Q is m x k) or complete (Q is m x m)
+pub fn qr(a: &ArrayView2<f64>, complete: bool) -> (Array2<f64>, Array2<f64>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let mut q = Array2::<f64>::eye(m);
+    let mut r = a.to_owned();
+
+    for j in 0..k.min(m.saturating_sub(1)) {
+        let mut v = r.slice(s![j.., j]).to_owned();
+        let norm = v.dot(&v).sqrt();
+
+        if norm == 0.0 {
+            continue;
+        }
+
+        v[0] -= if v[0] > 0.0 { -norm } else { norm };
+
+        let scale = 2.0 / v.dot(&v);
+
+        let mut r_sub = r.slice_mut(s![j.., j..]);
+        let w = v.dot(&r_sub) * scale;
+
+        for (mut row, vi) in r_sub.rows_mut().into_iter().zip(&v) {
+            row.scaled_add(-vi, &w);
+        }
+
+        let mut q_sub = q.slice_mut(s![.., j..]);
+        let w = q_sub.dot(&v) * scale;
+
+        for (mut row, wi) in q_sub.rows_mut().into_iter().zip(&w) {
+            row.scaled_add(-wi, &v);
+        }
+    }
+
+    r.indexed_iter_mut().filter(|((i, j), _)| i > j).for_each(|(_, v)| *v = 0.0);
+
+    if complete {
+        (q, r)
+
+    } else {
+        (q.slice(s![.., ..k]).to_owned(), r.slice(s![..k, ..]).to_owned())
+    }
+}
+
+pub fn cholesky(a: &ArrayView2<f64>) -> Array2<f64> {
+    let n = a.nrows();
+    let mut l = Array2::<f64>::zeros((n, n));
+
+    for j in 0..n {
+        let d = a[[j, j]] - l.slice(s![j, ..j]).dot(&l.slice(s![j, ..j]));
+
+        if d <= 0.0 || d.is_nan() {
+            rynd_error!("Unable to compute Cholesky decomposition: matrix is not positive definite");
+        }
+
+        l[[j, j]] = d.sqrt();
+
+        for i in j + 1..n {
+            l[[i, j]] = (a[[i, j]] - l.slice(s![i, ..j]).dot(&l.slice(s![j, ..j]))) / l[[j, j]];
+        }
+    }
+
+    l
+}
+
+fn rotate_columns(a: &mut Array2<f64>, p: usize, q: usize, c: f64, s: f64) {
+    for mut row in a.rows_mut() {
+        let (ap, aq) = (row[p], row[q]);
+        row[p] = c * ap - s * aq;
+        row[q] = s * ap + c * aq;
+    }
+}
+
+// One-sided Jacobi SVD (A = U * diag(S) * V^T, singular values in descending order)
+pub fn jacobi_svd(a: &ArrayView2<f64>) -> (Array2<f64>, Array1<f64>, Array2<f64>) {
+    // Work on the tall orientation so that U has orthogonal columns
+    if a.nrows() < a.ncols() {
+        let (u, s, v) = jacobi_svd(&a.t());
+        return (v, s, u);
+    }
+
+    let n = a.ncols();
+    let mut u = a.to_owned();
+    let mut v = Array2::<f64>::eye(n);
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = u.column(p).dot(&u.column(p));
+                let beta = u.column(q).dot(&u.column(q));
+                let gamma = u.column(p).dot(&u.column(q));
+
+                if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                    continue;
+                }
+
+                let zeta = (beta - alpha) / (2.0 * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                let c = 1.0 / (1.0 + t * t).sqrt();
+
+                rotate_columns(&mut u, p, q, c, c * t);
+                rotate_columns(&mut v, p, q, c, c * t);
+                rotated = true;
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    let norms = u.columns().into_iter().map(|c| c.dot(&c).sqrt()).collect::<Vec<_>>();
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| norms[j].total_cmp(&norms[i]));
+
+    let mut u = u.select(Axis(1), &order);
+    let v = v.select(Axis(1), &order);
+    let s = Array1::from_iter(order.iter().map(|&i| norms[i]));
+
+    for (mut col, sv) in u.columns_mut().into_iter().zip(&s) {
+        if *sv > 0.0 {
+            col /= *sv;
+        }
+    }
+
+    (u, s, v)
+}
+
+fn default_tolerance(s: &Array1<f64>, m: usize, n: usize) -> f64 {
+    s.iter().cloned().fold(0.0, f64::max) * m.max(n) as f64 * f64::EPSILON
+}
+
+// Keeps the first `valid` columns of an orthonormal set and completes it up to `cols` columns
+fn complete_basis(u: &Array2<f64>, valid: usize, cols: usize) -> Array2<f64> {
+    let m = u.nrows();
+    let mut basis = u.slice(s![.., ..valid]).to_owned();
+    let mut candidate = 0;
+
+    while basis.ncols() < cols && candidate < m {
+        let mut e = Array1::<f64>::zeros(m);
+        e[candidate] = 1.0;
+
+        // Gram-Schmidt is repeated twice to avoid losing orthogonality
+        for _ in 0..2 {
+            let proj = basis.t().dot(&e);
+            e -= &basis.dot(&proj);
+        }
+
+        let norm = e.dot(&e).sqrt();
+
+        if norm > 1e-8 {
+            basis.push_column((&e / norm).view()).unwrap();
+        }
+
+        candidate += 1;
+    }
+
+    basis
+}
+
+// Full or thin SVD (A = U * diag(S) * Vt)
+pub fn svd(a: &ArrayView2<f64>, full: bool) -> (Array2<f64>, Array1<f64>, Array2<f64>) {
+    let (m, n) = a.dim();
+    let k = m.min(n);
+    let (u, s, v) = jacobi_svd(a);
+
+    let tol = default_tolerance(&s, m, n);
+    let valid = s.iter().filter(|sv| **sv > tol).count();
+
+    let (u_cols, v_cols) = if full { (m, n) } else { (k, k) };
+
+    (complete_basis(&u, valid, u_cols), s, complete_basis(&v, valid, v_cols).reversed_axes())
+}
+
+// Cyclic Jacobi eigenvalue algorithm for symmetric matrices (uses the lower triangle)
+pub fn eigh(a: &ArrayView2<f64>) -> (Array1<f64>, Array2<f64>) {
+    let n = a.nrows();
+    let mut h = Array2::from_shape_fn((n, n), |(i, j)| if i >= j { a[[i, j]] } else { a[[j, i]] });
+    let mut v = Array2::<f64>::eye(n);
+
+    for sweep in 0..=MAX_JACOBI_SWEEPS {
+        let off = h.indexed_iter().filter(|((i, j), _)| i != j).map(|(_, x)| x * x).sum::<f64>();
+        let total = h.iter().map(|x| x * x).sum::<f64>();
+
+        if off <= f64::EPSILON * f64::EPSILON * total {
+            break;
+        }
+
+        if sweep == MAX_JACOBI_SWEEPS {
+            rynd_error!("Unable to compute eigenvalues: Jacobi algorithm did not converge");
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if h[[p, q]] == 0.0 {
+                    continue;
+                }
+
+                let theta = (h[[q, q]] - h[[p, p]]) / (2.0 * h[[p, q]]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                rotate_columns(&mut h, p, q, c, s);
+
+                for k in 0..n {
+                    let (hp, hq) = (h[[p, k]], h[[q, k]]);
+                    h[[p, k]] = c * hp - s * hq;
+                    h[[q, k]] = s * hp + c * hq;
+                }
+
+                // The rotation annihilates the pair, only rounding errors are left there
+                h[[p, q]] = 0.0;
+                h[[q, p]] = 0.0;
+
+                rotate_columns(&mut v, p, q, c, s);
+            }
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| h[[i, i]].total_cmp(&h[[j, j]]));
+
+    (Array1::from_iter(order.iter().map(|&i| h[[i, i]])), v.select(Axis(1), &order))
+}
+
+// Householder reduction to upper Hessenberg form (A = Q * H * Q^T)
+fn hessenberg(a: &ArrayView2<f64>) -> (Array2<f64>, Array2<f64>) {
+    let n = a.nrows();
+    let mut h = a.to_owned();
+    let mut q = Array2::<f64>::eye(n);
+
+    for j in 0..n.saturating_sub(2) {
+        let mut v = h.slice(s![j + 1.., j]).to_owned();
+        let norm = v.dot(&v).sqrt();
+
+        if norm == 0.0 {
+            continue;
+        }
+
+        v[0] -= if v[0] > 0.0 { -norm } else { norm };
+
+        let scale = 2.0 / v.dot(&v);
+
+        let mut rows = h.slice_mut(s![j + 1.., ..]);
+        let w = v.dot(&rows) * scale;
+
+        for (mut row, vi) in rows.rows_mut().into_iter().zip(&v) {
+            row.scaled_add(-vi, &w);
+        }
+
+        for mut cols in [h.slice_mut(s![.., j + 1..]), q.slice_mut(s![.., j + 1..])] {
+            let w = cols.dot(&v) * scale;
+
+            for (mut row, wi) in cols.rows_mut().into_iter().zip(&w) {
+                row.scaled_add(-wi, &v);
+            }
+        }
+    }
+
+    (h, q)
+}
+
+fn givens(x: Complex64, y: Complex64) -> (f64, Complex64) {
+    let r = (x.norm_sqr() + y.norm_sqr()).sqrt();
+
+    if r == 0.0 {
+        (1.0, Complex64::new(0.0, 0.0))
+
+    } else if x.norm() == 0.0 {
+        (0.0, Complex64::new(1.0, 0.0))
+
+    } else {
+        (x.norm() / r, x / x.norm() * y.conj() / r)
+    }
+}
+
+// Complex Schur decomposition (A = Z * T * Z^H) using shifted QR iterations over the Hessenberg form
+fn schur(a: &ArrayView2<f64>) -> (Array2<Complex64>, Array2<Complex64>) {
+    let n = a.nrows();
+    let (h, q) = hessenberg(a);
+    let mut t = h.mapv(|x| Complex64::new(x, 0.0));
+    let mut z = q.mapv(|x| Complex64::new(x, 0.0));
+    let mut hi = n;
+    let mut its = 0;
+
+    while hi > 0 {
+        let end = hi - 1;
+        let mut lo = end;
+
+        while lo > 0 {
+            let scale = t[[lo - 1, lo - 1]].norm() + t[[lo, lo]].norm();
+
+            if t[[lo, lo - 1]].norm() <= f64::EPSILON * scale {
+                t[[lo, lo - 1]] = Complex64::new(0.0, 0.0);
+                break;
+            }
+
+            lo -= 1;
+        }
+
+        if lo == end {
+            hi -= 1;
+            its = 0;
+            continue;
+        }
+
+        if its == MAX_QR_ITERATIONS {
+            rynd_error!("Unable to compute eigenvalues: QR algorithm did not converge");
+        }
+
+        its += 1;
+
+        // Wilkinson shift (with exceptional shifts to break cycles)
+        let (a, b, c, d) = (t[[end - 1, end - 1]], t[[end - 1, end]], t[[end, end - 1]], t[[end, end]]);
+
+        let mu = if its % 10 == 0 {
+            d + t[[end, end - 1]].norm()
+
+        } else {
+            let half_tr = (a + d) / 2.0;
+            let disc = ((a - d) * (a - d) / 4.0 + b * c).sqrt();
+            let (l1, l2) = (half_tr + disc, half_tr - disc);
+
+            if (l1 - d).norm() < (l2 - d).norm() { l1 } else { l2 }
+        };
+
+        for k in lo..=end {
+            t[[k, k]] -= mu;
+        }
+
+        // Rotations are applied to the whole matrix (not only the active block) to keep T and Z consistent
+        let mut rotations = Vec::with_capacity(end - lo);
+
+        for k in lo..end {
+            let (c, s) = givens(t[[k, k]], t[[k + 1, k]]);
+
+            for j in k..n {
+                let (x, y) = (t[[k, j]], t[[k + 1, j]]);
+                t[[k, j]] = x * c + s * y;
+                t[[k + 1, j]] = -s.conj() * x + y * c;
+            }
+
+            rotations.push((c, s));
+        }
+
+        for (k, (c, s)) in (lo..end).zip(rotations) {
+            for i in 0..=(k + 2).min(end) {
+                let (x, y) = (t[[i, k]], t[[i, k + 1]]);
+                t[[i, k]] = x * c + y * s.conj();
+                t[[i, k + 1]] = -x * s + y * c;
+            }
+
+            for i in 0..n {
+                let (x, y) = (z[[i, k]], z[[i, k + 1]]);
+                z[[i, k]] = x * c + y * s.conj();
+                z[[i, k + 1]] = -x * s + y * c;
+            }
+        }
+
+        for k in lo..=end {
+            t[[k, k]] += mu;
+        }
+    }
+
+    (t, z)
+}
+
+// Eigenvectors of an upper triangular matrix by back-substitution over (T - lambda_k * I) y = 0 with y_k = 1.
+// Near-zero pivots (repeated eigenvalues) are replaced by a small perturbation, as LAPACK's trevc does
+fn triangular_eigenvectors(t: &Array2<Complex64>) -> Array2<Complex64> {
+    let n = t.nrows();
+    let norm = t.iter().map(|x| x.norm()).sum::<f64>();
+    let smin = (norm * f64::EPSILON).max(f64::MIN_POSITIVE);
+    let mut y = Array2::<Complex64>::zeros((n, n));
+
+    for k in 0..n {
+        let lambda = t[[k, k]];
+        y[[k, k]] = Complex64::new(1.0, 0.0);
+
+        for i in (0..k).rev() {
+            let acc = (i + 1..=k).map(|j| t[[i, j]] * y[[j, k]]).sum::<Complex64>();
+            let mut d = t[[i, i]] - lambda;
+
+            if d.norm() < smin {
+                d = Complex64::new(smin, 0.0);
+            }
+
+            y[[i, k]] = -acc / d;
+        }
+    }
+
+    y
+}
+
+// General eigendecomposition, returned as real and imaginary parts of eigenvalues and eigenvectors
+pub fn eig(a: &ArrayView2<f64>) -> (Array1<f64>, Array1<f64>, Array2<f64>, Array2<f64>) {
+    let n = a.nrows();
+    let norm = a.iter().map(|x| x.abs()).sum::<f64>();
+    let (t, z) = schur(a);
+    let mut values = t.diag().to_vec();
+
+    // Eigenvalues of real matrices are either real or come in conjugate pairs
+    for v in values.iter_mut() {
+        if v.im.abs() <= n as f64 * f64::EPSILON * norm {
+            v.im = 0.0;
+        }
+    }
+
+    let mut vectors = z.dot(&triangular_eigenvectors(&t));
+
+    for (mut x, v) in vectors.columns_mut().into_iter().zip(&values) {
+        // Normalize the phase so that the largest component is real, which makes the vectors of real
+        // eigenvalues real up to rounding errors
+        let largest = *x.iter().max_by(|a, b| a.norm().total_cmp(&b.norm())).unwrap();
+
+        if largest.norm() > 0.0 {
+            x /= largest / largest.norm();
+        }
+
+        if v.im == 0.0 {
+            x.mapv_inplace(|c| Complex64::new(c.re, 0.0));
+        }
+
+        let x_norm = x.iter().map(|c| c.norm_sqr()).sum::<f64>().sqrt();
+
+        if x_norm > 0.0 {
+            x /= Complex64::new(x_norm, 0.0);
+        }
+    }
+
+    (
+        Array1::from_iter(values.iter().map(|v| v.re)),
+        Array1::from_iter(values.iter().map(|v| v.im)),
+        vectors.mapv(|v| v.re),
+        vectors.mapv(|v| v.im)
+    )
+}
+
+fn pseudo_inverse_values(s: &Array1<f64>, cutoff: f64) -> Array1<f64> {
+    s.mapv(|v| if v > cutoff { 1.0 / v } else { 0.0 })
+}
+
+pub fn pinv(a: &ArrayView2<f64>, rcond: Option<f64>) -> Array2<f64> {
+    let (u, s, vt) = svd(a, false);
+    let cutoff = rcond.unwrap_or(1e-15) * s.iter().cloned().fold(0.0, f64::max);
+
+    (&vt.t() * &pseudo_inverse_values(&s, cutoff)).dot(&u.t())
+}
+
+// Least squares solution of AX = B, returned alongside the residuals, the rank of A and its singular values
+pub fn lstsq(a: &ArrayView2<f64>, b: &ArrayViewD<f64>, rcond: Option<f64>) -> (ArrayD<f64>, Array1<f64>, usize, Array1<f64>) {
+    let (m, n) = a.dim();
+    let (u, s, vt) = svd(a, false);
+
+    let cutoff = rcond.unwrap_or(f64::EPSILON * m.max(n) as f64) * s.iter().cloned().fold(0.0, f64::max);
+    let rank = s.iter().filter(|v| **v > cutoff).count();
+
+    let b_mat = if b.ndim() == 1 {
+        b.view().insert_axis(Axis(1)).into_dimensionality::<Ix2>().unwrap()
+
+    } else {
+        b.view().into_dimensionality::<Ix2>().unwrap()
+    };
+
+    let s_inv = pseudo_inverse_values(&s, cutoff).insert_axis(Axis(1));
+    let x = vt.t().dot(&(u.t().dot(&b_mat) * &s_inv));
+
+    // Residuals are only well defined for full rank overdetermined systems
+    let residuals = if rank == n && m > n {
+        (&b_mat - &a.dot(&x)).mapv(|v| v * v).sum_axis(Axis(0))
+
+    } else {
+        Array1::zeros(0)
+    };
+
+    let x = if b.ndim() == 1 { x.remove_axis(Axis(1)).into_dyn() } else { x.into_dyn() };
+
+    (x, residuals, rank, s)
+}
+
+pub fn vector_norm<'a>(x: impl Iterator<Item = &'a f64>, p: f64) -> f64 {
+    if p == f64::INFINITY {
+        x.fold(0.0, |acc, v| acc.max(v.abs()))
+
+    } else if p == f64::NEG_INFINITY {
+        x.fold(f64::INFINITY, |acc, v| acc.min(v.abs()))
+
+    } else if p == 0.0 {
+        x.filter(|v| **v != 0.0).count() as f64
+
+    } else if p == 1.0 {
+        x.map(|v| v.abs()).sum()
+
+    } else if p == 2.0 {
+        x.map(|v| v * v).sum::<f64>().sqrt()
+
+    } else {
+        x.map(|v| v.abs().powf(p)).sum::<f64>().powf(1.0 / p)
+    }
+}
+
+pub fn matrix_norm(a: &ArrayView2<f64>, norm: MatrixNorm) -> f64 {
+    match norm {
+        MatrixNorm::Frobenius => vector_norm(a.iter(), 2.0),
+        MatrixNorm::Nuclear => jacobi_svd(a).1.sum(),
+        MatrixNorm::Spectral => jacobi_svd(a).1.iter().cloned().fold(0.0, f64::max),
+    }
+}
+
+pub fn matrix_rank(a: &ArrayView2<f64>, tol: Option<f64>) -> usize {
+    let (_, s, _) = jacobi_svd(a);
+    let tol = tol.unwrap_or_else(|| default_tolerance(&s, a.nrows(), a.ncols()));
+
+    s.iter().filter(|sv| **sv > tol).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array2};
+
+    use super::*;
+
+    fn assert_close(a: &ArrayViewD<f64>, b: &ArrayViewD<f64>) {
+        assert_eq!(a.shape(), b.shape());
+        assert!(a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-9), "{a} != {b}");
+    }
+
+    fn sample() -> Array2<f64> {
+        array![[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]]
+    }
+
+    #[test]
+    fn solve_vector_and_matrix() {
+        let a = sample();
+        let x = solve(&a.view(), &array![5.0, -2.0, 9.0].into_dyn().view());
+        assert_close(&x.view(), &array![1.0, 1.0, 2.0].into_dyn().view());
+
+        let b = array![[5.0, 1.0], [-2.0, 0.0], [9.0, 2.0]].into_dyn();
+        let x = solve(&a.view(), &b.view());
+        assert_close(&a.dot(&x.into_dimensionality::<Ix2>().unwrap()).into_dyn().view(), &b.view());
+    }
+
+    #[test]
+    fn inverse() {
+        let a = sample();
+        let res = a.dot(&inv(&a.view()));
+        assert_close(&res.into_dyn().view(), &Array2::<f64>::eye(3).into_dyn().view());
+    }
+
+    #[test]
+    fn rounding_pivots_are_singular() {
+        // Elimination leaves a pivot of about 1e-16 instead of an exact zero
+        let a = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        let factors = lu_factor(&a.view());
+
+        assert!(!factors.singular);
+        assert!(is_numerically_singular(&factors, &a.view()));
+        assert!(!is_numerically_singular(&lu_factor(&sample().view()), &sample().view()));
+        assert!(!is_numerically_singular(&lu_factor(&(sample() * 1e-200).view()), &(sample() * 1e-200).view()));
+    }
+
+    #[test]
+    fn determinants() {
+        let a = sample();
+        assert!((det(&a.view()) + 16.0).abs() < 1e-9);
+        assert_eq!(det(&array![[1.0, 2.0], [2.0, 4.0]].view()), 0.0);
+
+        let (sign, logdet) = slogdet(&a.view());
+        assert_eq!(sign, -1.0);
+        assert!((logdet - 16f64.ln()).abs() < 1e-9);
+        assert_eq!(slogdet(&Array2::zeros((2, 2)).view()), (0.0, f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn rank() {
+        assert_eq!(matrix_rank(&sample().view(), None), 3);
+        assert_eq!(matrix_rank(&array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]].view(), None), 1);
+        assert_eq!(matrix_rank(&Array2::zeros((2, 3)).view(), None), 0);
+    }
+
+    fn assert_orthonormal(q: &Array2<f64>) {
+        assert_close(&q.t().dot(q).into_dyn().view(), &Array2::<f64>::eye(q.ncols()).into_dyn().view());
+    }
+
+    fn dense(n: usize) -> Array2<f64> {
+        Array2::from_shape_fn((n, n), |(i, j)| ((i * 7 + j * 13) as f64).sin())
+    }
+
+    #[test]
+    fn lu_reconstruction() {
+        for a in [sample(), array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], array![[0.0, 1.0], [1.0, 0.0], [2.0, 3.0]]] {
+            let (p, l, u) = lu(&a.view());
+            assert_close(&p.dot(&l).dot(&u).into_dyn().view(), &a.view().into_dyn());
+            assert!(l.indexed_iter().all(|((i, j), v)| i >= j || *v == 0.0));
+            assert!(u.indexed_iter().all(|((i, j), v)| i <= j || *v == 0.0));
+        }
+    }
+
+    #[test]
+    fn qr_reconstruction() {
+        let a = array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+
+        for complete in [false, true] {
+            let (q, r) = qr(&a.view(), complete);
+            assert_eq!(q.dim(), if complete { (3, 3) } else { (3, 2) });
+            assert_orthonormal(&q);
+            assert_close(&q.dot(&r).into_dyn().view(), &a.view().into_dyn());
+            assert!(r.indexed_iter().all(|((i, j), v)| i <= j || *v == 0.0));
+        }
+    }
+
+    #[test]
+    fn cholesky_reconstruction() {
+        let a = array![[4.0, 12.0, -16.0], [12.0, 37.0, -43.0], [-16.0, -43.0, 98.0]];
+        let l = cholesky(&a.view());
+        assert_close(&l.view().into_dyn(), &array![[2.0, 0.0, 0.0], [6.0, 1.0, 0.0], [-8.0, 5.0, 3.0]].into_dyn().view());
+    }
+
+    #[test]
+    fn svd_reconstruction() {
+        for a in [sample(), array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]] {
+            let (m, n) = a.dim();
+            let k = m.min(n);
+
+            for full in [false, true] {
+                let (u, s, vt) = svd(&a.view(), full);
+                assert_eq!((u.dim(), vt.dim()), if full { ((m, m), (n, n)) } else { ((m, k), (k, n)) });
+                assert_orthonormal(&u);
+                assert_orthonormal(&vt.t().to_owned());
+                assert!(s.windows(2).into_iter().all(|w| w[0] >= w[1]));
+
+                let res = (&u.slice(s![.., ..k]) * &s).dot(&vt.slice(s![..k, ..]));
+                assert_close(&res.into_dyn().view(), &a.view().into_dyn());
+            }
+        }
+    }
+
+    #[test]
+    fn symmetric_eigendecomposition() {
+        let base = dense(8);
+
+        for a in [array![[2.0, 1.0], [1.0, 2.0]], Array2::eye(3), &base + &base.t()] {
+            let (w, v) = eigh(&a.view());
+            assert!(w.windows(2).into_iter().all(|w| w[0] <= w[1]));
+            assert_orthonormal(&v);
+            assert_close(&a.dot(&v).into_dyn().view(), &(&v * &w).into_dyn().view());
+        }
+
+        assert_close(&eigh(&array![[2.0, 1.0], [1.0, 2.0]].view()).0.into_dyn().view(), &array![1.0, 3.0].into_dyn().view());
+    }
+
+    fn assert_eigenpairs(a: &Array2<f64>) {
+        let (wr, wi, vr, vi) = eig(&a.view());
+        let a = a.mapv(|x| Complex64::new(x, 0.0));
+        let w = Array1::from_iter(wr.iter().zip(&wi).map(|(r, i)| Complex64::new(*r, *i)));
+        let v = Array2::from_shape_fn(vr.dim(), |idx| Complex64::new(vr[idx], vi[idx]));
+
+        let diff = a.dot(&v) - &v * &w;
+        assert!(diff.iter().all(|d| d.norm() < 1e-8), "{diff}");
+
+        for col in v.columns() {
+            assert!((col.iter().map(|c| c.norm_sqr()).sum::<f64>() - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn general_eigendecomposition() {
+        for a in [sample(), dense(6), array![[0.0, -1.0], [1.0, 0.0]], array![[2.0, 1.0], [0.0, 2.0]]] {
+            assert_eigenpairs(&a);
+        }
+
+        let (wr, wi, _, _) = eig(&array![[0.0, -1.0], [1.0, 0.0]].view());
+        assert_close(&wr.into_dyn().view(), &array![0.0, 0.0].into_dyn().view());
+        assert_close(&wi.mapv(f64::abs).into_dyn().view(), &array![1.0, 1.0].into_dyn().view());
+    }
+
+    #[test]
+    fn repeated_eigenvalues_have_independent_eigenvectors() {
+        let (p, _) = qr(&dense(3).view(), true);
+        let d = Array2::from_diag(&array![2.0, 2.0, 5.0]);
+
+        for a in [Array2::eye(3), Array2::from_elem((1, 1), 4.0), p.dot(&d).dot(&p.t())] {
+            assert_eigenpairs(&a);
+
+            let (_, _, vr, vi) = eig(&a.view());
+            assert!(vi.iter().all(|v| *v == 0.0));
+            assert!(det(&vr.view()).abs() > 1e-6);
+        }
+
+        let (_, _, vr, _) = eig(&Array2::eye(3).view());
+        assert_close(&vr.into_dyn().view(), &Array2::<f64>::eye(3).into_dyn().view());
+    }
+
+    #[test]
+    fn integer_matrix_powers() {
+        let a = array![[1.0, 1.0], [1.0, 0.0]];
+        assert_close(&matrix_power(&a.view(), 10).into_dyn().view(), &array![[89.0, 55.0], [55.0, 34.0]].into_dyn().view());
+        assert_close(&matrix_power(&a.view(), 0).into_dyn().view(), &Array2::<f64>::eye(2).into_dyn().view());
+        assert_close(&matrix_power(&a.view(), -2).into_dyn().view(), &array![[1.0, -1.0], [-1.0, 2.0]].into_dyn().view());
+    }
+
+    #[test]
+    fn pseudo_inverse() {
+        let a = sample();
+        assert_close(&pinv(&a.view(), None).into_dyn().view(), &inv(&a.view()).into_dyn().view());
+
+        // Moore-Penrose conditions of a rank deficient matrix
+        let a = array![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let p = pinv(&a.view(), None);
+        assert_eq!(p.dim(), (2, 3));
+        assert_close(&a.dot(&p).dot(&a).into_dyn().view(), &a.view().into_dyn());
+        assert_close(&p.dot(&a).dot(&p).into_dyn().view(), &p.view().into_dyn());
+    }
+
+    #[test]
+    fn least_squares() {
+        // Line fit through (0, 1), (1, 3), (2, 4), (3, 4)
+        let a = array![[0.0, 1.0], [1.0, 1.0], [2.0, 1.0], [3.0, 1.0]];
+        let (x, residuals, rank, s) = lstsq(&a.view(), &array![1.0, 3.0, 4.0, 4.0].into_dyn().view(), None);
+
+        assert_close(&x.view(), &array![1.0, 1.5].into_dyn().view());
+        assert_close(&residuals.into_dyn().view(), &array![1.0].into_dyn().view());
+        assert_eq!((rank, s.len()), (2, 2));
+
+        let (x, residuals, rank, _) = lstsq(&array![[1.0, 2.0], [2.0, 4.0]].view(), &array![[1.0], [2.0]].into_dyn().view(), None);
+        assert_close(&x.view(), &array![[0.2], [0.4]].into_dyn().view());
+        assert_eq!((residuals.len(), rank), (0, 1));
+    }
+
+    #[test]
+    fn norms() {
+        let x = [3.0, -4.0, 0.0];
+        assert_eq!(vector_norm(x.iter(), 2.0), 5.0);
+        assert_eq!(vector_norm(x.iter(), 1.0), 7.0);
+        assert_eq!(vector_norm(x.iter(), 0.0), 2.0);
+        assert_eq!(vector_norm(x.iter(), f64::INFINITY), 4.0);
+        assert_eq!(vector_norm(x.iter(), f64::NEG_INFINITY), 0.0);
+        assert!((vector_norm(x.iter(), 3.0) - 91f64.cbrt()).abs() < 1e-12);
+
+        let a = array![[3.0, 0.0], [0.0, -4.0]];
+        assert_eq!(matrix_norm(&a.view(), MatrixNorm::Frobenius), 5.0);
+        assert!((matrix_norm(&a.view(), MatrixNorm::Nuclear) - 7.0).abs() < 1e-12);
+        assert!((matrix_norm(&a.view(), MatrixNorm::Spectral) - 4.0).abs() < 1e-12);
+    }
+}
@@ -9,6 +9,9 @@ lazy_static! {
     static ref REFCOUNTS: Mutex<FxHashMap<usize, usize>> = Mutex::default();
     static ref DEPS_VIEW_ARR: Mutex<FxHashMap<usize, usize>> = Mutex::default();
     static ref DEPS_ARR_VIEW: Mutex<FxHashMap<usize, FxHashSet<usize>>> = Mutex::default();
+    static ref READONLY: Mutex<FxHashSet<usize>> = Mutex::default();
+    static ref GENERATORS: Mutex<FxHashSet<usize>> = Mutex::default();
+    static ref LISTS: Mutex<FxHashSet<usize>> = Mutex::default();
 }
 
 pub fn ptr_to_ref<'a>(ptr: *const c_void) -> &'a mut NDArray {
@@ -30,12 +33,25 @@ pub fn register_view(arr: *const c_void, view: *const c_void) {
                 .entry(view as usize)
                 .or_insert(arr_ptr as usize);
 
+    // Views of read-only arrays are also read-only
+    if is_readonly(arr) {
+        set_readonly(view);
+    }
+
     DEPS_ARR_VIEW.lock().unwrap()
                 .entry(arr_ptr as usize)
                 .or_default()
                 .insert(view as usize);
 }
 
+pub fn set_readonly(ptr: *const c_void) {
+    READONLY.lock().unwrap().insert(ptr as usize);
+}
+
+pub fn is_readonly(ptr: *const c_void) -> bool {
+    READONLY.lock().unwrap().contains(&(ptr as usize))
+}
+
 fn array_has_view(arr: *const c_void) -> bool {
     DEPS_ARR_VIEW.lock().unwrap().contains_key(&(arr as usize))
 }
@@ -43,6 +59,7 @@ fn array_has_view(arr: *const c_void) -> bool {
 fn remove_view(view: *const c_void) {
     if can_remove_view(view) {
         REFCOUNTS.lock().unwrap().remove(&(view as usize));
+        READONLY.lock().unwrap().remove(&(view as usize));
 
         unsafe { std::ptr::drop_in_place(view as *mut NDArray) };
 
@@ -114,4 +131,65 @@ pub fn register_and_leak(obj: Box<NDArray>) -> *const c_void {
     register_ref(ptr);
 
     ptr
-}
\ No newline at end of file
+}
+
+pub fn register_and_leak_list(objs: Vec<NDArray>) -> *const c_void {
+    let ptrs = objs.into_iter()
+                   .map(|obj| register_and_leak(Box::new(obj)))
+                   .collect::<Vec<_>>();
+
+    let ptr = Box::leak(Box::new(ptrs)) as *const Vec<*const c_void> as *const c_void;
+
+    LISTS.lock().unwrap().insert(ptr as usize);
+
+    ptr
+}
+
+pub fn is_list(ptr: *const c_void) -> bool {
+    LISTS.lock().unwrap().contains(&(ptr as usize))
+}
+
+pub fn list_ptr_to_ref<'a>(ptr: *const c_void) -> &'a mut Vec<*const c_void> {
+    if !is_list(ptr) { // Avoid dereferencing an invalid list
+        rynd_error!("Tried to use deleted array list");
+    }
+
+    unsafe { &mut *(ptr as *mut Vec<*const c_void>) }
+}
+
+pub fn free_list_ptr(ptr: *const c_void) {
+    if !LISTS.lock().unwrap().remove(&(ptr as usize)) { // Avoid double free
+        rynd_error!("Tried to free deleted array list");
+    }
+
+    // Only the list is freed, the arrays it contains are owned by the caller
+    unsafe { drop(Box::from_raw(ptr as *mut Vec<*const c_void>)) };
+}
+
+pub fn register_generator(ptr: *const c_void) {
+    GENERATORS.lock().unwrap().insert(ptr as usize);
+}
+
+pub fn is_generator(ptr: *const c_void) -> bool {
+    GENERATORS.lock().unwrap().contains(&(ptr as usize))
+}
+
+pub fn remove_generator(ptr: *const c_void) -> bool {
+    GENERATORS.lock().unwrap().remove(&(ptr as usize))
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_handles_are_registered() {
+        let ptr = register_and_leak_list(vec![]);
+        assert!(is_list(ptr));
+
+        list_ptr_to_ref(ptr).push(std::ptr::null());
+        assert_eq!(list_ptr_to_ref(ptr).len(), 1);
+
+        free_list_ptr(ptr);
+        assert!(!is_list(ptr));
+    }
+}
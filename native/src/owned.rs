@@ -2,13 +2,23 @@ use ndarray::{Array1, ArrayD};
 use rand::Rng;
 use rand_distr::{Distribution, Normal};
 
-use crate::view::NDArrayView;
+use crate::{algorithms::{eye, pad, PadMode}, view::NDArrayView};
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 pub enum NDArrayType {
     Int, Float, Bool
 }
 
+impl NDArrayType {
+    pub fn promote(&self, other: &NDArrayType) -> NDArrayType {
+        match (self, other) {
+            (NDArrayType::Float, _) | (_, NDArrayType::Float) => NDArrayType::Float,
+            (NDArrayType::Int, _) | (_, NDArrayType::Int) => NDArrayType::Int,
+            _ => NDArrayType::Bool
+        }
+    }
+}
+
 impl TryFrom<usize> for NDArrayType {
     type Error = ();
 
@@ -66,6 +76,43 @@ impl NDArrayOwned {
         }
     }
 
+    pub fn full_i64(tp: NDArrayType, shape: Vec<usize>, value: i64) -> Self {
+        match tp {
+            NDArrayType::Int => NDArrayOwned::Int(ArrayD::from_elem(shape, value)),
+            NDArrayType::Float => NDArrayOwned::Float(ArrayD::from_elem(shape, value as f64)),
+            NDArrayType::Bool => NDArrayOwned::Bool(ArrayD::from_elem(shape, value != 0)),
+        }
+    }
+
+    pub fn full_f64(tp: NDArrayType, shape: Vec<usize>, value: f64) -> Self {
+        match tp {
+            NDArrayType::Int => NDArrayOwned::Int(ArrayD::from_elem(shape, value as i64)),
+            NDArrayType::Float => NDArrayOwned::Float(ArrayD::from_elem(shape, value)),
+            NDArrayType::Bool => NDArrayOwned::Bool(ArrayD::from_elem(shape, value != 0.0)),
+        }
+    }
+
+    pub fn ones(tp: NDArrayType, shape: Vec<usize>) -> Self {
+        Self::full_i64(tp, shape, 1)
+    }
+
+    // Reading uninitialized integers or floats is undefined behaviour, so "empty" arrays are zeroed
+    pub fn empty(tp: NDArrayType, shape: Vec<usize>) -> Self {
+        match tp {
+            NDArrayType::Int => NDArrayOwned::Int(ArrayD::zeros(shape)),
+            NDArrayType::Float => NDArrayOwned::Float(ArrayD::zeros(shape)),
+            NDArrayType::Bool => NDArrayOwned::Bool(ArrayD::default(shape)),
+        }
+    }
+
+    pub fn dtype(&self) -> NDArrayType {
+        match self {
+            NDArrayOwned::Int(_) => NDArrayType::Int,
+            NDArrayOwned::Float(_) => NDArrayType::Float,
+            NDArrayOwned::Bool(_) => NDArrayType::Bool,
+        }
+    }
+
     pub fn len(&self) -> usize {
         match_op!(self, a, a.len())
     }
@@ -78,8 +125,15 @@ impl NDArrayOwned {
         NDArrayOwned::from(Array1::<i64>::from_iter(0..l).into_dyn())
     }
 
-    pub fn rand(shape: Vec<usize>) -> Self {
-        let mut rng = rand::rng();
+    pub fn eye(tp: NDArrayType, n: usize, m: usize, k: i64) -> Self {
+        match tp {
+            NDArrayType::Int => NDArrayOwned::from(eye(n, m, k, 1i64).into_dyn()),
+            NDArrayType::Float => NDArrayOwned::from(eye(n, m, k, 1.0).into_dyn()),
+            NDArrayType::Bool => NDArrayOwned::from(eye(n, m, k, true).into_dyn()),
+        }
+    }
+
+    pub fn rand<R: Rng + ?Sized>(rng: &mut R, shape: Vec<usize>) -> Self {
         let mut result = ArrayD::<f64>::zeros(shape);
 
         result.mapv_inplace(|_| rng.random());
@@ -87,24 +141,83 @@ impl NDArrayOwned {
         result.into()
     }
 
-    pub fn normal(mean: f64, std: f64, shape: Vec<usize>) -> Self {
-        let mut rng = rand::rng();
+    pub fn normal<R: Rng + ?Sized>(rng: &mut R, mean: f64, std: f64, shape: Vec<usize>) -> Self {
         let normal = Normal::new(mean, std).unwrap();
         let mut result = ArrayD::<f64>::zeros(shape);
 
-        result.mapv_inplace(|_| normal.sample(&mut rng));
+        result.mapv_inplace(|_| normal.sample(rng));
 
         result.into()
     }
 
-    pub fn linspace(f: i64, t: i64, s: usize) -> Self {
-        NDArrayOwned::from(Array1::<i64>::from_iter((f..t).step_by(s)).into_dyn())
+    pub fn from_fn<T, F: FnMut() -> T>(shape: Vec<usize>, f: F) -> Self where ArrayD<T>: Into<Self> {
+        ArrayD::from_shape_simple_fn(shape, f).into()
+    }
+
+    pub fn arange_i64(start: i64, stop: i64, step: i64) -> Self {
+        let n = if step > 0 {
+            ((stop - start).max(0) + step - 1) / step
+        } else {
+            ((start - stop).max(0) - step - 1) / -step
+        };
+
+        NDArrayOwned::from(Array1::<i64>::from_iter((0..n).map(|i| start + i * step)).into_dyn())
+    }
+
+    pub fn arange_f64(start: f64, stop: f64, step: f64) -> Self {
+        let n = ((stop - start) / step).ceil().max(0.0) as usize;
+
+        NDArrayOwned::from(Array1::<f64>::from_iter((0..n).map(|i| start + i as f64 * step)).into_dyn())
+    }
+
+    fn linspace_values(start: f64, stop: f64, num: usize, endpoint: bool) -> Array1<f64> {
+        let div = if endpoint { num.saturating_sub(1) } else { num };
+        let step = if div > 0 { (stop - start) / div as f64 } else { 0.0 };
+        let mut result = Array1::<f64>::from_iter((0..num).map(|i| start + i as f64 * step));
+
+        // Avoid rounding errors on the last element
+        if endpoint && num > 1 {
+            result[num - 1] = stop;
+        }
+
+        result
+    }
+
+    pub fn linspace(start: f64, stop: f64, num: usize, endpoint: bool) -> Self {
+        NDArrayOwned::from(Self::linspace_values(start, stop, num, endpoint).into_dyn())
+    }
+
+    pub fn logspace(start: f64, stop: f64, num: usize, endpoint: bool, base: f64) -> Self {
+        NDArrayOwned::from(Self::linspace_values(start, stop, num, endpoint).mapv(|i| base.powf(i)).into_dyn())
+    }
+
+    pub fn geomspace(start: f64, stop: f64, num: usize, endpoint: bool) -> Self {
+        let sign = start.signum();
+        let mut result = Self::linspace_values(start.abs().log10(), stop.abs().log10(), num, endpoint).mapv(|i| sign * 10f64.powf(i));
+
+        if num > 0 {
+            result[0] = start;
+        }
+
+        if endpoint && num > 1 {
+            result[num - 1] = stop;
+        }
+
+        NDArrayOwned::from(result.into_dyn())
     }
 
     pub fn view(&mut self) -> NDArrayView {
         match_op!(self, a, a.raw_view_mut().into())
     }
 
+    pub fn pad(&self, widths: &[(usize, usize)], mode: PadMode, constant: f64) -> Self {
+        match self {
+            NDArrayOwned::Int(a) => pad(&a.view(), widths, mode, constant as i64).into(),
+            NDArrayOwned::Float(a) => pad(&a.view(), widths, mode, constant).into(),
+            NDArrayOwned::Bool(a) => pad(&a.view(), widths, mode, constant != 0.0).into(),
+        }
+    }
+
     pub fn cast(&mut self, tp: NDArrayType) -> Self {
         match (tp, self) {
             (NDArrayType::Int, NDArrayOwned::Int(array)) => NDArrayOwned::from(array.clone()),
@@ -128,4 +241,63 @@ impl std::fmt::Display for NDArrayOwned {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match_op!(self, a, write!(f, "{a}"))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_values(a: NDArrayOwned, expected: &[f64]) {
+        let values = match a {
+            NDArrayOwned::Int(a) => a.iter().map(|v| *v as f64).collect::<Vec<_>>(),
+            NDArrayOwned::Float(a) => a.iter().cloned().collect(),
+            NDArrayOwned::Bool(a) => a.iter().map(|v| *v as i64 as f64).collect(),
+        };
+
+        assert_eq!(values.len(), expected.len(), "{values:?} != {expected:?}");
+        assert!(values.iter().zip(expected).all(|(a, b)| (a - b).abs() <= 1e-9 * b.abs().max(1.0)), "{values:?} != {expected:?}");
+    }
+
+    #[test]
+    fn ranges() {
+        assert_values(NDArrayOwned::arange_i64(0, 10, 3), &[0.0, 3.0, 6.0, 9.0]);
+        assert_values(NDArrayOwned::arange_i64(10, 0, -3), &[10.0, 7.0, 4.0, 1.0]);
+        assert_values(NDArrayOwned::arange_i64(0, 5, -1), &[]);
+
+        assert_values(NDArrayOwned::arange_f64(0.0, 1.0, 0.25), &[0.0, 0.25, 0.5, 0.75]);
+        assert_values(NDArrayOwned::arange_f64(1.0, 0.0, -0.4), &[1.0, 0.6, 0.2]);
+        assert_values(NDArrayOwned::arange_f64(0.0, 1.0, -1.0), &[]);
+    }
+
+    #[test]
+    fn evenly_spaced() {
+        assert_values(NDArrayOwned::linspace(0.0, 1.0, 5, true), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_values(NDArrayOwned::linspace(0.0, 1.0, 5, false), &[0.0, 0.2, 0.4, 0.6, 0.8]);
+        assert_values(NDArrayOwned::linspace(3.0, 7.0, 1, true), &[3.0]);
+        assert_values(NDArrayOwned::linspace(3.0, 7.0, 0, true), &[]);
+
+        assert_values(NDArrayOwned::logspace(0.0, 3.0, 4, true, 10.0), &[1.0, 10.0, 100.0, 1000.0]);
+        assert_values(NDArrayOwned::logspace(0.0, 3.0, 3, false, 2.0), &[1.0, 2.0, 4.0]);
+
+        assert_values(NDArrayOwned::geomspace(1.0, 1000.0, 4, true), &[1.0, 10.0, 100.0, 1000.0]);
+        assert_values(NDArrayOwned::geomspace(-1.0, -1000.0, 4, true), &[-1.0, -10.0, -100.0, -1000.0]);
+        assert_values(NDArrayOwned::geomspace(1.0, 16.0, 4, false), &[1.0, 2.0, 4.0, 8.0]);
+    }
+
+    #[test]
+    fn filled_constructors() {
+        let a = NDArrayOwned::full_i64(NDArrayType::Bool, vec![2, 2], 1);
+        assert!(a.dtype() == NDArrayType::Bool && a.shape() == [2, 2]);
+        assert_values(a, &[1.0; 4]);
+
+        assert_values(NDArrayOwned::full_f64(NDArrayType::Int, vec![3], 2.7), &[2.0; 3]);
+        assert_values(NDArrayOwned::full_f64(NDArrayType::Float, vec![2], 2.5), &[2.5; 2]);
+        assert_values(NDArrayOwned::ones(NDArrayType::Float, vec![2, 0]), &[]);
+
+        for tp in [NDArrayType::Int, NDArrayType::Float, NDArrayType::Bool] {
+            let a = NDArrayOwned::empty(tp.clone(), vec![3, 4]);
+            assert!(a.dtype() == tp && a.shape() == [3, 4]);
+            assert_values(a, &[0.0; 12]);
+        }
+    }
+}
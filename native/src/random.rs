@@ -0,0 +1,294 @@
+use std::{fmt::Display, os::raw::c_void};
+
+use ndarray::{Array2, ArrayD, ArrayView1, ArrayView2, Axis, IxDyn};
+use rand::{distr::weighted::WeightedIndex, rngs::ThreadRng, seq::{index, SliceRandom}, Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+use rand_distr::{Distribution, Gamma, StandardNormal};
+use rynaffi::FFIValue;
+
+use crate::{linalg::eigh, memory::{is_generator, register_generator, remove_generator}, rynd_error};
+
+// ChaCha12 with seeds expanded by `seed_from_u64` has a documented, platform-independent output
+// stream, so a given seed always produces the same arrays with the rand, rand_chacha and rand_distr
+// versions pinned in Cargo.toml
+pub type Generator = ChaCha12Rng;
+
+pub enum RyndRng<'a> {
+    Seeded(&'a mut Generator),
+    Thread(ThreadRng)
+}
+
+impl RngCore for RyndRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            RyndRng::Seeded(r) => r.next_u32(),
+            RyndRng::Thread(r) => r.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            RyndRng::Seeded(r) => r.next_u64(),
+            RyndRng::Thread(r) => r.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        match self {
+            RyndRng::Seeded(r) => r.fill_bytes(dst),
+            RyndRng::Thread(r) => r.fill_bytes(dst),
+        }
+    }
+}
+
+pub fn create_generator(seed: Option<u64>) -> *const c_void {
+    let generator = match seed {
+        Some(s) => Generator::seed_from_u64(s),
+        None => Generator::from_rng(&mut rand::rng()),
+    };
+
+    let ptr = Box::leak(Box::new(generator)) as *const Generator as *const c_void;
+
+    register_generator(ptr);
+
+    ptr
+}
+
+pub fn generator_ptr_to_ref<'a>(ptr: *const c_void) -> &'a mut Generator {
+    if !is_generator(ptr) { // Avoid dereferencing an invalid generator
+        rynd_error!("Tried to use deleted random generator");
+    }
+
+    unsafe { &mut *(ptr as *mut Generator) }
+}
+
+pub fn free_generator_ptr(ptr: *const c_void) {
+    if !remove_generator(ptr) { // Avoid double free
+        rynd_error!("Tried to free deleted random generator");
+    }
+
+    unsafe { drop(Box::from_raw(ptr as *mut Generator)) };
+}
+
+pub fn seed_generator(generator: &mut Generator, seed: u64) {
+    *generator = Generator::seed_from_u64(seed);
+}
+
+// Skips the next n 64-bit outputs (each one takes two 32-bit words of the ChaCha stream)
+pub fn advance_generator(generator: &mut Generator, n: u64) {
+    let pos = generator.get_word_pos();
+    generator.set_word_pos(pos + 2 * n as u128);
+}
+
+// Random functions receive either a generator handle or 0 to use the thread generator
+pub fn rng_from_ffi<'a>(value: &FFIValue) -> RyndRng<'a> {
+    match value {
+        FFIValue::Pointer(p) => RyndRng::Seeded(generator_ptr_to_ref(*p)),
+        _ => RyndRng::Thread(rand::rng()),
+    }
+}
+
+pub fn distribution<D, E: Display>(name: &str, dist: Result<D, E>) -> D {
+    match dist {
+        Ok(d) => d,
+        Err(e) => rynd_error!("Invalid parameters for {} distribution ({})", name, e),
+    }
+}
+
+// Samples are generated as mean + F z, with F F^T = cov obtained from the eigendecomposition
+// of the covariance matrix (this also works for singular covariances)
+pub fn multivariate_normal<R: Rng + ?Sized>(rng: &mut R, mean: &ArrayView1<f64>, cov: &ArrayView2<f64>, shape: Vec<usize>) -> ArrayD<f64> {
+    let d = mean.len();
+    let n = shape.iter().product::<usize>();
+
+    if cov.dim() != (d, d) {
+        rynd_error!("Incompatible mean and covariance shapes for multivariate normal ({:?} and {:?})", mean.shape(), cov.shape());
+    }
+
+    // eigh only reads the lower triangle, so asymmetric covariances would be silently symmetrized
+    let max_c = cov.iter().fold(0.0f64, |a, b| a.max(b.abs()));
+
+    if cov.indexed_iter().any(|((i, j), c)| (c - cov[[j, i]]).abs() > 1e-8 * max_c) {
+        rynd_error!("Covariance matrix must be symmetric");
+    }
+
+    let (w, v) = eigh(cov);
+
+    let max_w = w.iter().fold(0.0f64, |a, b| a.max(b.abs()));
+
+    if w.iter().any(|i| *i < -1e-8 * max_w.max(1.0)) {
+        rynd_error!("Covariance matrix must be positive semi-definite");
+    }
+
+    let mut factor = v;
+
+    for (mut col, wi) in factor.axis_iter_mut(Axis(1)).zip(w.iter()) {
+        col *= wi.max(0.0).sqrt();
+    }
+
+    let z = Array2::<f64>::from_shape_simple_fn((n, d), || rng.sample(StandardNormal));
+    let samples = z.dot(&factor.t()) + mean;
+
+    let mut out_shape = shape;
+    out_shape.push(d);
+
+    samples.into_shape_with_order(IxDyn(&out_shape)).unwrap()
+}
+
+// Each sample is a vector of independent Gamma(alpha_i, 1) variables divided by their sum
+pub fn dirichlet<R: Rng + ?Sized>(rng: &mut R, alpha: &ArrayView1<f64>, shape: Vec<usize>) -> ArrayD<f64> {
+    let k = alpha.len();
+    let n = shape.iter().product::<usize>();
+
+    let gammas = alpha.iter()
+                      .map(|a| distribution("gamma", Gamma::new(*a, 1.0)))
+                      .collect::<Vec<_>>();
+
+    let mut samples = Array2::<f64>::from_shape_fn((n, k), |(_, j)| gammas[j].sample(rng));
+
+    for mut row in samples.axis_iter_mut(Axis(0)) {
+        let total = row.sum();
+        row /= total;
+    }
+
+    let mut out_shape = shape;
+    out_shape.push(k);
+
+    samples.into_shape_with_order(IxDyn(&out_shape)).unwrap()
+}
+
+pub fn permutation<R: Rng + ?Sized>(rng: &mut R, n: usize) -> Vec<i64> {
+    let mut res = (0..n as i64).collect::<Vec<_>>();
+    res.shuffle(rng);
+
+    res
+}
+
+// Indices of the elements picked by a random choice out of n elements, optionally weighted by p
+pub fn choice_indices<R: Rng + ?Sized>(rng: &mut R, n: usize, size: usize, replace: bool, p: Option<&[f64]>) -> Vec<usize> {
+    if let Some(w) = p {
+        if w.len() != n {
+            rynd_error!("Expected {} probabilities for random choice (got {})", n, w.len());
+        }
+    }
+
+    if n == 0 && size > 0 {
+        rynd_error!("Unable to choose elements from an empty array");
+    }
+
+    match (replace, p) {
+        (true, None) => (0..size).map(|_| rng.random_range(0..n)).collect(),
+        (true, Some(w)) => {
+            let dist = distribution("weighted", WeightedIndex::new(w));
+            (0..size).map(|_| dist.sample(rng)).collect()
+        },
+
+        (false, _) if size > n => rynd_error!("Unable to choose {} elements out of {} without replacement", size, n),
+        (false, None) => index::sample(rng, n, size).into_vec(),
+        (false, Some(w)) => distribution("weighted", index::sample_weighted(rng, n, |i| w[i], size)).into_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(generator: &mut Generator, n: usize) -> Vec<u64> {
+        (0..n).map(|_| generator.next_u64()).collect()
+    }
+
+    #[test]
+    fn generator_handles_are_registered() {
+        let ptr = create_generator(Some(1));
+        assert!(is_generator(ptr));
+
+        free_generator_ptr(ptr);
+        assert!(!is_generator(ptr));
+    }
+
+    #[test]
+    fn seeds_are_reproducible() {
+        let ptr = create_generator(Some(42));
+        let generator = generator_ptr_to_ref(ptr);
+        let first = draw(generator, 4);
+
+        assert_eq!(first, draw(&mut Generator::seed_from_u64(42), 4));
+        assert_ne!(first, draw(&mut Generator::seed_from_u64(43), 4));
+
+        seed_generator(generator, 42);
+        assert_eq!(first, draw(generator, 4));
+
+        free_generator_ptr(ptr);
+    }
+
+    #[test]
+    fn advancing_skips_outputs() {
+        let mut generator = Generator::seed_from_u64(7);
+        let values = draw(&mut generator, 5);
+
+        let mut advanced = Generator::seed_from_u64(7);
+        advance_generator(&mut advanced, 3);
+        assert_eq!(draw(&mut advanced, 2), values[3..]);
+    }
+
+    #[test]
+    fn multivariate_normal_moments() {
+        let mut rng = Generator::seed_from_u64(0);
+        let mean = ndarray::array![1.0, -2.0];
+        let cov = ndarray::array![[2.0, 0.6], [0.6, 1.0]];
+
+        let samples = multivariate_normal(&mut rng, &mean.view(), &cov.view(), vec![20000]);
+        assert_eq!(samples.shape(), [20000, 2]);
+
+        let samples = samples.into_dimensionality::<ndarray::Ix2>().unwrap();
+        let centered = &samples - &samples.mean_axis(Axis(0)).unwrap();
+        let sample_cov = centered.t().dot(&centered) / 20000.0;
+
+        assert!((samples.mean_axis(Axis(0)).unwrap() - &mean).iter().all(|d| d.abs() < 0.05));
+        assert!((sample_cov - &cov).iter().all(|d| d.abs() < 0.05));
+
+        // Singular covariances are allowed and keep the samples on a line
+        let singular = ndarray::array![[1.0, 1.0], [1.0, 1.0]];
+        let samples = multivariate_normal(&mut rng, &ndarray::array![0.0, 0.0].view(), &singular.view(), vec![2, 3]);
+        assert_eq!(samples.shape(), [2, 3, 2]);
+        assert!(samples.lanes(Axis(2)).into_iter().all(|s| (s[0] - s[1]).abs() < 1e-9));
+
+        // Asymmetries from rounding errors are tolerated
+        let rounded = ndarray::array![[2.0, 0.6 + 1e-15], [0.6, 1.0]];
+        assert_eq!(multivariate_normal(&mut rng, &mean.view(), &rounded.view(), vec![3]).shape(), [3, 2]);
+    }
+
+    #[test]
+    fn dirichlet_samples_are_on_the_simplex() {
+        let mut rng = Generator::seed_from_u64(0);
+        let samples = dirichlet(&mut rng, &ndarray::array![0.5, 1.0, 4.0].view(), vec![4, 5]);
+
+        assert_eq!(samples.shape(), [4, 5, 3]);
+        assert!(samples.iter().all(|v| *v >= 0.0));
+        assert!(samples.lanes(Axis(2)).into_iter().all(|s| (s.sum() - 1.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn permutations_and_choices() {
+        let mut rng = Generator::seed_from_u64(5);
+
+        let mut perm = permutation(&mut rng, 10);
+        perm.sort();
+        assert_eq!(perm, (0..10).collect::<Vec<_>>());
+
+        let picked = choice_indices(&mut rng, 5, 100, true, None);
+        assert!(picked.len() == 100 && picked.iter().all(|i| *i < 5));
+
+        let mut picked = choice_indices(&mut rng, 5, 5, false, None);
+        picked.sort();
+        assert_eq!(picked, [0, 1, 2, 3, 4]);
+
+        // Elements with zero weight are never picked
+        let weights = [0.0, 1.0, 0.0, 3.0];
+        assert!(choice_indices(&mut rng, 4, 100, true, Some(&weights)).iter().all(|i| *i == 1 || *i == 3));
+
+        let mut picked = choice_indices(&mut rng, 4, 2, false, Some(&weights));
+        picked.sort();
+        assert_eq!(picked, [1, 3]);
+    }
+}
@@ -0,0 +1,95 @@
+use ndarray::Slice;
+
+// Python slice semantics translated to ndarray slices. Missing bounds default to the whole dimension
+// in the direction of the step, negative bounds count from the end and every bound is clamped to the
+// dimension instead of raising an error. Reference cases for a dimension of size 5 (same as NumPy):
+//
+//  ::1       [0, 1, 2, 3, 4]   ::-1      [4, 3, 2, 1, 0]
+//  1::1      [1, 2, 3, 4]      3::-1     [3, 2, 1, 0]
+//  :3:1      [0, 1, 2]         :1:-1     [4, 3, 2]
+//  1:-1:1    [1, 2, 3]         -1:-4:-1  [4, 3, 2]
+//  -3::1     [2, 3, 4]         4:0:-2    [4, 2]
+//  -10:10:1  [0, 1, 2, 3, 4]   10:-10:-1 [4, 3, 2, 1, 0]
+//  3:1:1     []                1:3:-1    []
+//  7:9:1     []                ::-3      [4, 1]
+//  ::2       [0, 2, 4]
+
+// Resolves a bound to an absolute position between lower and upper (both inclusive)
+fn resolve_bound(bound: isize, len: isize, lower: isize, upper: isize) -> isize {
+    let bound = if bound < 0 { bound + len } else { bound };
+
+    bound.clamp(lower, upper)
+}
+
+// ndarray always slices the range [start, end) and negative steps traverse it from the back, so
+// Python's (start, end] range for negative steps is shifted by one
+pub fn normalize_slice(start: Option<isize>, end: Option<isize>, step: isize, len: usize) -> Slice {
+    let len = len as isize;
+
+    if step > 0 {
+        let start = start.map(|s| resolve_bound(s, len, 0, len)).unwrap_or(0);
+        let end = end.map(|e| resolve_bound(e, len, 0, len)).unwrap_or(len);
+
+        Slice::new(start, Some(end.max(start)), step)
+
+    } else {
+        let start = start.map(|s| resolve_bound(s, len, -1, len - 1)).unwrap_or(len - 1);
+        let end = end.map(|e| resolve_bound(e, len, -1, len - 1)).unwrap_or(-1);
+
+        Slice::new(end + 1, Some((start + 1).max(end + 1)), step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{Array1, Axis};
+
+    use super::*;
+
+    fn sliced(len: usize, start: Option<isize>, end: Option<isize>, step: isize) -> Vec<i64> {
+        let arr = Array1::from_iter(0..len as i64);
+
+        arr.slice_axis(Axis(0), normalize_slice(start, end, step, len)).to_vec()
+    }
+
+    #[test]
+    fn positive_steps() {
+        assert_eq!(sliced(5, None, None, 1), [0, 1, 2, 3, 4]);
+        assert_eq!(sliced(5, Some(1), None, 1), [1, 2, 3, 4]);
+        assert_eq!(sliced(5, None, Some(3), 1), [0, 1, 2]);
+        assert_eq!(sliced(5, Some(1), Some(-1), 1), [1, 2, 3]);
+        assert_eq!(sliced(5, Some(-3), None, 1), [2, 3, 4]);
+        assert_eq!(sliced(5, Some(-10), Some(10), 1), [0, 1, 2, 3, 4]);
+        assert_eq!(sliced(5, Some(3), Some(1), 1), []);
+        assert_eq!(sliced(5, Some(7), Some(9), 1), []);
+        assert_eq!(sliced(5, None, None, 2), [0, 2, 4]);
+    }
+
+    #[test]
+    fn negative_steps() {
+        assert_eq!(sliced(5, None, None, -1), [4, 3, 2, 1, 0]);
+        assert_eq!(sliced(5, Some(3), None, -1), [3, 2, 1, 0]);
+        assert_eq!(sliced(5, None, Some(1), -1), [4, 3, 2]);
+        assert_eq!(sliced(5, Some(-1), Some(-4), -1), [4, 3, 2]);
+        assert_eq!(sliced(5, Some(4), Some(0), -2), [4, 2]);
+        assert_eq!(sliced(5, Some(10), Some(-10), -1), [4, 3, 2, 1, 0]);
+        assert_eq!(sliced(5, Some(1), Some(3), -1), []);
+        assert_eq!(sliced(5, None, None, -3), [4, 1]);
+    }
+
+    #[test]
+    fn empty_dimensions() {
+        assert_eq!(sliced(0, None, None, 1), []);
+        assert_eq!(sliced(0, None, None, -1), []);
+        assert_eq!(sliced(0, Some(-1), Some(1), 1), []);
+        assert_eq!(sliced(0, Some(1), Some(-1), -1), []);
+    }
+
+    #[test]
+    fn steps_larger_than_the_dimension() {
+        assert_eq!(sliced(5, None, None, 7), [0]);
+        assert_eq!(sliced(5, Some(2), None, 7), [2]);
+        assert_eq!(sliced(5, None, None, -7), [4]);
+        assert_eq!(sliced(5, Some(1), None, -7), [1]);
+    }
+}
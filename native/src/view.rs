@@ -1,29 +1,57 @@
-use ndarray::{Array1, ArrayBase, ArrayViewD, Axis, Dim, Ix2, IxDynImpl, OwnedRepr, RawArrayViewMut, Slice, Zip};
+use ndarray::{Array1, Array2, ArrayBase, ArrayD, ArrayViewD, ArrayViewMutD, Axis, Dim, Ix1, Ix2, IxDyn, IxDynImpl, OwnedRepr, RawArrayView, RawArrayViewMut, Slice, Zip};
+use rand::Rng;
 
-use crate::{algorithms::{argmax_axis, argmin_axis, argsort_axis, concat_axis, cumsum_axis, diff_axis, max_axis, min_axis, nonzero, reverse_axis, sort_view_axis, stack_axis}, owned::NDArrayOwned, rynd_error};
+use crate::{algorithms::{log_add_exp, log_add_exp2, nan_max, nan_min, next_after, zip_broadcast, ScatterOp, TakeMode, advanced_index, argmax_axis, argmin_axis, argsort_axis, choose, concat_axis, cumsum_axis, diag_from, diagonal_axis, diff_axis, max_axis, min_axis, nonzero, put_along_axis, reverse_axis, scatter, shuffle_view, sort_view_axis, stack_axis, take, take_along_axis, tile, triangle, where_cond}, linalg, owned::{NDArrayOwned, NDArrayType}, rynd_error};
 
 type DynRawArrayView<T> = RawArrayViewMut<T, Dim<IxDynImpl>>;
+
+// Broadcast views repeat elements through zero strides, which ndarray only allows in immutable views,
+// so they are kept as read-only raw views that can never be dereferenced as mutable
+#[derive(Clone)]
+pub enum RawView<T> {
+    Mut(DynRawArrayView<T>),
+    Const(RawArrayView<T, IxDyn>),
+}
+
 #[derive(Clone)]
 pub enum NDArrayView {
-    Int(DynRawArrayView<i64>),
-    Float(DynRawArrayView<f64>),
-    Bool(DynRawArrayView<bool>),
+    Int(RawView<i64>),
+    Float(RawView<f64>),
+    Bool(RawView<bool>),
 }
 
 impl From<DynRawArrayView<i64>> for NDArrayView {
     fn from(value: DynRawArrayView<i64>) -> Self {
+        Self::Int(RawView::Mut(value))
+    }
+}
+
+impl From<RawView<i64>> for NDArrayView {
+    fn from(value: RawView<i64>) -> Self {
         Self::Int(value)
     }
 }
 
 impl From<DynRawArrayView<f64>> for NDArrayView {
     fn from(value: DynRawArrayView<f64>) -> Self {
+        Self::Float(RawView::Mut(value))
+    }
+}
+
+impl From<RawView<f64>> for NDArrayView {
+    fn from(value: RawView<f64>) -> Self {
         Self::Float(value)
     }
 }
 
 impl From<DynRawArrayView<bool>> for NDArrayView {
     fn from(value: DynRawArrayView<bool>) -> Self {
+        Self::Bool(RawView::Mut(value))
+    }
+}
+
+impl From<RawView<bool>> for NDArrayView {
+    fn from(value: RawView<bool>) -> Self {
         Self::Bool(value)
     }
 }
@@ -54,6 +82,51 @@ macro_rules! scalar_fn {
     };
 }
 
+// Functions are given as paths or closures taking two values of the promoted type
+macro_rules! scalar_map_fn {
+    ($obj: expr, $n: ident, $reverse: ident, $scalar: expr, $func_f: expr, $func_i: expr, $func_l: expr) => {
+        match ($obj, $reverse) {
+            (NDArrayView::Int($n), false) => view!($n).mapv(|i| $func_i(i, $scalar as i64)).into(),
+            (NDArrayView::Float($n), false) => view!($n).mapv(|i| $func_f(i, $scalar as f64)).into(),
+            (NDArrayView::Bool($n), false) => view!($n).mapv(|i| $func_l(i, $scalar as i64 != 0)).into(),
+            (NDArrayView::Int($n), true) => view!($n).mapv(|i| $func_i($scalar as i64, i)).into(),
+            (NDArrayView::Float($n), true) => view!($n).mapv(|i| $func_f($scalar as f64, i)).into(),
+            (NDArrayView::Bool($n), true) => view!($n).mapv(|i| $func_l($scalar as i64 != 0, i)).into(),
+        }
+    };
+}
+
+macro_rules! scalar_fn_def {
+    ($name1: ident, $name2: ident, $func_f: expr, $func_i: expr, $func_l: expr) => {
+        pub fn $name1(&self, scalar: i64, reverse: bool) -> NDArrayOwned {
+            scalar_map_fn!(self, a, reverse, scalar, $func_f, $func_i, $func_l)
+        }
+        
+        pub fn $name2(&self, scalar: f64, reverse: bool) -> NDArrayOwned {
+            scalar_map_fn!(self, a, reverse, scalar, $func_f, $func_i, $func_l)
+        }
+    };
+}
+
+// Float functions always return Float arrays, so the scalar is never truncated
+macro_rules! scalar_float_fn_def {
+    ($name1: ident, $name2: ident, $func: expr) => {
+        pub fn $name1(&self, scalar: i64, reverse: bool) -> NDArrayOwned {
+            self.$name2(scalar as f64, reverse)
+        }
+
+        pub fn $name2(&self, scalar: f64, reverse: bool) -> NDArrayOwned {
+            let f = |i: f64| if reverse { $func(scalar, i) } else { $func(i, scalar) };
+
+            match self {
+                NDArrayView::Int(a) => view!(a).mapv(|i| f(i as f64)).into(),
+                NDArrayView::Float(a) => view!(a).mapv(f).into(),
+                NDArrayView::Bool(a) => view!(a).mapv(|i| f(i as i64 as f64)).into(),
+            }
+        }
+    };
+}
+
 macro_rules! scalar_op_def {
     ($name1: ident, $name2: ident, $op: tt, $l_op: tt) => {
         pub fn $name1(&self, scalar: i64, reverse: bool) -> NDArrayOwned {
@@ -88,6 +161,18 @@ macro_rules! view_mut {
     };
 }
 
+// Applies the same operation to a raw view of either kind, so derived views stay read-only
+macro_rules! map_raw {
+    ($raw: expr, $r: ident, $op: expr) => {
+        match $raw.clone() {
+            #[allow(unused_mut)]
+            RawView::Mut(mut $r) => RawView::Mut($op),
+            #[allow(unused_mut)]
+            RawView::Const(mut $r) => RawView::Const($op),
+        }
+    };
+}
+
 macro_rules! arr_zip {
     ($a: ident, $b: ident, $op: expr) => {
         Zip::from(view!($a)).and(view!($b)).map_collect(|$a, $b| $op)
@@ -118,6 +203,40 @@ macro_rules! broadcast_op {
 
 
 
+macro_rules! broadcast_zip_fn {
+    ($a: ident, $b: ident, $func_f: expr, $func_i: expr, $func_l: expr) => {
+        match ($a, $b) {
+            (NDArrayView::Int(a), NDArrayView::Int(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_i(*x, *y)).into(),
+            (NDArrayView::Int(a), NDArrayView::Float(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_f(*x as f64, *y)).into(),
+            (NDArrayView::Int(a), NDArrayView::Bool(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_i(*x, *y as i64)).into(),
+            (NDArrayView::Float(a), NDArrayView::Int(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_f(*x, *y as f64)).into(),
+            (NDArrayView::Float(a), NDArrayView::Float(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_f(*x, *y)).into(),
+            (NDArrayView::Float(a), NDArrayView::Bool(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_f(*x, *y as i64 as f64)).into(),
+            (NDArrayView::Bool(a), NDArrayView::Int(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_i(*x as i64, *y)).into(),
+            (NDArrayView::Bool(a), NDArrayView::Float(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_f(*x as i64 as f64, *y)).into(),
+            (NDArrayView::Bool(a), NDArrayView::Bool(b)) => zip_broadcast(view!(a), view!(b), |x, y| $func_l(*x, *y)).into(),
+        }
+    };
+}
+
+macro_rules! zip_fn_def {
+    ($name: ident, $func_f: expr, $func_i: expr, $func_l: expr) => {
+        pub fn $name(&self, other: &NDArrayView) -> NDArrayOwned {
+            broadcast_zip_fn!(self, other, $func_f, $func_i, $func_l)
+        }
+    };
+}
+
+macro_rules! float_fn_def {
+    ($name: ident, $func: expr) => {
+        zip_fn_def!(
+            $name, $func, 
+            |x: i64, y: i64| $func(x as f64, y as f64), 
+            |x: bool, y: bool| $func(x as i64 as f64, y as i64 as f64)
+        );
+    };
+}
+
 macro_rules! broadcast_bitwise_op {
     ($a: ident, $b: ident, $aa: ident, $bb: ident, $op: tt) => {
         match ($a, $b) {
@@ -160,21 +279,75 @@ macro_rules! unary_float_fn {
 
 macro_rules! unary_mapv_float_fn {
     ($name: ident) => {
+        unary_mapv_float_fn!($name, $name);
+    };
+
+    ($name: ident, $method: ident) => {
         pub fn $name(&self) -> NDArrayOwned {
             match self {
-                NDArrayView::Int(a) => view!(a).mapv(|i| (i as f64).$name()).into(),
-                NDArrayView::Float(a) => view!(a).mapv(|i| i.$name()).into(),
-                NDArrayView::Bool(a) => view!(a).mapv(|i| (i as i64 as f64).$name()).into(),
+                NDArrayView::Int(a) => view!(a).mapv(|i| (i as f64).$method()).into(),
+                NDArrayView::Float(a) => view!(a).mapv(|i| i.$method()).into(),
+                NDArrayView::Bool(a) => view!(a).mapv(|i| (i as i64 as f64).$method()).into(),
             }
         }
     };
 }
 
+impl<T> RawView<T> {
+    pub fn shape(&self) -> &[usize] {
+        match self {
+            RawView::Mut(r) => r.shape(),
+            RawView::Const(r) => r.shape(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match self {
+            RawView::Mut(r) => r.len(),
+            RawView::Const(r) => r.len(),
+        }
+    }
+
+    unsafe fn deref_into_view<'a>(self) -> ArrayViewD<'a, T> {
+        match self {
+            RawView::Mut(r) => r.deref_into_view(),
+            RawView::Const(r) => r.deref_into_view(),
+        }
+    }
+
+    unsafe fn deref_into_view_mut<'a>(self) -> ArrayViewMutD<'a, T> {
+        match self {
+            RawView::Mut(r) => r.deref_into_view_mut(),
+            RawView::Const(_) => rynd_error!("Unable to write to a read-only broadcast array"),
+        }
+    }
+}
+
 impl NDArrayView {
     pub fn owned(&self) -> NDArrayOwned {
         match_op!(self, v, view!(v).to_owned().into())
     }
 
+    pub fn to_f64(&self) -> ArrayD<f64> {
+        match self {
+            NDArrayView::Int(a) => view!(a).mapv(|i| i as f64),
+            NDArrayView::Float(a) => view!(a).to_owned(),
+            NDArrayView::Bool(a) => view!(a).mapv(|i| i as i64 as f64),
+        }
+    }
+
+    pub fn to_i64(&self) -> ArrayD<i64> {
+        match self {
+            NDArrayView::Int(a) => view!(a).to_owned(),
+            NDArrayView::Float(a) => view!(a).mapv(|i| i as i64),
+            NDArrayView::Bool(a) => view!(a).mapv(|i| i as i64),
+        }
+    }
+
+    fn float_matrix(&self) -> Array2<f64> {
+        self.to_f64().into_dimensionality::<Ix2>().unwrap()
+    }
+
     pub fn assign(&self, other: &NDArrayView) {
         match (self, other) {
             (NDArrayView::Int(a), NDArrayView::Int(b)) => view_mut!(a).zip_mut_with(view!(b), |i, v| *i = *v),
@@ -208,6 +381,14 @@ impl NDArrayView {
         }
     }
 
+    pub fn dtype(&self) -> NDArrayType {
+        match self {
+            NDArrayView::Int(_) => NDArrayType::Int,
+            NDArrayView::Float(_) => NDArrayType::Float,
+            NDArrayView::Bool(_) => NDArrayType::Bool,
+        }
+    }
+
     pub fn len(&self) -> usize {
         match_op!(self, a, a.len())
     }
@@ -352,6 +533,19 @@ impl NDArrayView {
         }
     }
 
+    zip_fn_def!(maximum, nan_max, i64::max, |x: bool, y: bool| x | y);
+    zip_fn_def!(minimum, nan_min, i64::min, |x: bool, y: bool| x & y);
+    zip_fn_def!(fmax, f64::max, i64::max, |x: bool, y: bool| x | y);
+    zip_fn_def!(fmin, f64::min, i64::min, |x: bool, y: bool| x & y);
+
+    float_fn_def!(atan2, f64::atan2);
+    float_fn_def!(hypot, f64::hypot);
+    float_fn_def!(copysign, f64::copysign);
+    float_fn_def!(nextafter, next_after);
+    float_fn_def!(logaddexp, log_add_exp);
+    float_fn_def!(logaddexp2, log_add_exp2);
+    float_fn_def!(float_power, f64::powf);
+
     scalar_op_def!(sum_scalar_i64, sum_scalar_f64, +, ^);
     scalar_op_def!(sub_scalar_i64, sub_scalar_f64, -, ^);
     scalar_op_def!(mul_scalar_i64, mul_scalar_f64, *, &);
@@ -362,6 +556,19 @@ impl NDArrayView {
     scalar_op_def!(leq_scalar_i64, leq_scalar_f64, <=, <=);
     scalar_op_def!(geq_scalar_i64, geq_scalar_f64, >=, >=);
 
+    scalar_fn_def!(maximum_scalar_i64, maximum_scalar_f64, nan_max, i64::max, |x: bool, y: bool| x | y);
+    scalar_fn_def!(minimum_scalar_i64, minimum_scalar_f64, nan_min, i64::min, |x: bool, y: bool| x & y);
+    scalar_fn_def!(fmax_scalar_i64, fmax_scalar_f64, f64::max, i64::max, |x: bool, y: bool| x | y);
+    scalar_fn_def!(fmin_scalar_i64, fmin_scalar_f64, f64::min, i64::min, |x: bool, y: bool| x & y);
+
+    scalar_float_fn_def!(atan2_scalar_i64, atan2_scalar_f64, f64::atan2);
+    scalar_float_fn_def!(hypot_scalar_i64, hypot_scalar_f64, f64::hypot);
+    scalar_float_fn_def!(copysign_scalar_i64, copysign_scalar_f64, f64::copysign);
+    scalar_float_fn_def!(nextafter_scalar_i64, nextafter_scalar_f64, next_after);
+    scalar_float_fn_def!(logaddexp_scalar_i64, logaddexp_scalar_f64, log_add_exp);
+    scalar_float_fn_def!(logaddexp2_scalar_i64, logaddexp2_scalar_f64, log_add_exp2);
+    scalar_float_fn_def!(float_power_scalar_i64, float_power_scalar_f64, f64::powf);
+
     pub fn div_scalar_i64(&self, scalar: i64, reverse: bool) -> NDArrayOwned {
         if matches!(self, NDArrayView::Bool(_)) {
             rynd_error!("Unable to divide boolean array");
@@ -410,7 +617,7 @@ impl NDArrayView {
         scalar_fn!(self, a, reverse, scalar, powf, pow, &)
     }
 
-    fn mask<T>(v: &DynRawArrayView<T>, mask: &DynRawArrayView<bool>) -> NDArrayOwned 
+    fn mask<T>(v: &RawView<T>, mask: &RawView<bool>) -> NDArrayOwned 
         where NDArrayOwned: From<ArrayBase<OwnedRepr<T>, Dim<IxDynImpl>>>,
               T: Clone { 
         if v.shape() != mask.shape() {
@@ -452,7 +659,7 @@ impl NDArrayView {
         v[mapped_idx.as_slice()].clone()
     }
 
-    fn array_index<T>(v: &DynRawArrayView<T>, index: &DynRawArrayView<i64>) -> NDArrayOwned 
+    fn array_index<T>(v: &RawView<T>, index: &RawView<i64>) -> NDArrayOwned 
         where NDArrayOwned: From<ArrayBase<OwnedRepr<T>, Dim<IxDynImpl>>>,
               T: Clone { 
         if !Self::valid_index(v, index) {
@@ -474,7 +681,7 @@ impl NDArrayView {
         }
     }
 
-    fn valid_index<T>(v: &DynRawArrayView<T>, index: &DynRawArrayView<i64>) -> bool {
+    fn valid_index<T>(v: &RawView<T>, index: &RawView<i64>) -> bool {
         (
             v.shape().len() == *index.shape().last().unwrap() ||
             (v.shape().len() == 1 && index.shape().len() == 1)
@@ -494,91 +701,209 @@ impl NDArrayView {
         }
     }
 
+    pub fn advanced_index(&self, indices: &[(usize, NDArrayView)]) -> NDArrayOwned {
+        let idx = indices.iter().map(|(d, i)| match i {
+            NDArrayView::Int(a) => (*d, view!(a).clone()),
+            _ => unreachable!()
+        }).collect::<Vec<_>>();
+
+        match_op!(self, a, advanced_index(view!(a), &idx).into())
+    }
+
+    pub fn scatter(&self, indices: &[NDArrayView], values: &NDArrayView, op: ScatterOp) {
+        let idx = indices.iter().map(|i| match i {
+            NDArrayView::Int(a) => view!(a).clone(),
+            _ => unreachable!()
+        }).collect::<Vec<_>>();
+
+        match (self, values) {
+            (NDArrayView::Int(a), NDArrayView::Int(b)) => scatter(view_mut!(a), &idx, view!(b), op),
+            (NDArrayView::Float(a), NDArrayView::Float(b)) => scatter(view_mut!(a), &idx, view!(b), op),
+            (NDArrayView::Bool(a), NDArrayView::Bool(b)) => scatter(view_mut!(a), &idx, view!(b), op),
+            _ => unreachable!()
+        }
+    }
+
+    pub fn take(&self, axis: usize, indices: &[usize], idx_shape: &[usize]) -> NDArrayOwned {
+        match_op!(self, a, take(view!(a), axis, indices, idx_shape).into())
+    }
+
+    pub fn take_along_axis(&self, indices: &NDArrayView, axis: usize) -> NDArrayOwned {
+        match indices {
+            NDArrayView::Int(i) => match_op!(self, a, take_along_axis(view!(a), view!(i), axis).into()),
+            _ => unreachable!()
+        }
+    }
+
+    pub fn put_along_axis(&self, indices: &NDArrayView, values: &NDArrayView, axis: usize) {
+        let idx = match indices {
+            NDArrayView::Int(i) => view!(i).clone(),
+            _ => unreachable!()
+        };
+
+        match (self, values) {
+            (NDArrayView::Int(a), NDArrayView::Int(b)) => put_along_axis(view_mut!(a), &idx, view!(b), axis),
+            (NDArrayView::Float(a), NDArrayView::Float(b)) => put_along_axis(view_mut!(a), &idx, view!(b), axis),
+            (NDArrayView::Bool(a), NDArrayView::Bool(b)) => put_along_axis(view_mut!(a), &idx, view!(b), axis),
+            _ => unreachable!()
+        }
+    }
+
+    pub fn where_cond(cond: &NDArrayView, x: &NDArrayView, y: &NDArrayView) -> NDArrayOwned {
+        match (cond, x, y) {
+            (NDArrayView::Bool(c), NDArrayView::Int(a), NDArrayView::Int(b)) => where_cond(view!(c), view!(a), view!(b)).into(),
+            (NDArrayView::Bool(c), NDArrayView::Float(a), NDArrayView::Float(b)) => where_cond(view!(c), view!(a), view!(b)).into(),
+            (NDArrayView::Bool(c), NDArrayView::Bool(a), NDArrayView::Bool(b)) => where_cond(view!(c), view!(a), view!(b)).into(),
+            _ => unreachable!()
+        }
+    }
+
+    pub fn choose(indices: &NDArrayView, choices: &[NDArrayView], mode: TakeMode) -> NDArrayOwned {
+        macro_rules! choices_of {
+            ($variant: ident) => {
+                choices.iter().map(|c| match c {
+                    NDArrayView::$variant(a) => view!(a).clone(),
+                    _ => unreachable!()
+                }).collect::<Vec<_>>()
+            };
+        }
+
+        let idx = match indices {
+            NDArrayView::Int(i) => view!(i).clone(),
+            _ => unreachable!()
+        };
+
+        match choices[0] {
+            NDArrayView::Int(_) => choose(&idx, &choices_of!(Int), mode).into(),
+            NDArrayView::Float(_) => choose(&idx, &choices_of!(Float), mode).into(),
+            NDArrayView::Bool(_) => choose(&idx, &choices_of!(Bool), mode).into(),
+        }
+    }
+
     pub fn reshape(&mut self, shape: Vec<usize>) -> NDArrayView {
-        match_op!(self, a, view_mut!(a).into_shape_with_order(shape).unwrap().raw_view_mut().into())
+        match_op!(self, a, map_raw!(a, r, r.into_shape_with_order(shape.clone()).unwrap()).into())
     }
 
     pub fn slice(&mut self, slices: Vec<Slice>) -> NDArrayView {
-        match_op!(self, a, view_mut!(a).slice_each_axis_mut(|ax| {
-            slices.get(ax.axis.index()).cloned().unwrap_or_else(|| Slice::new(0, None, 1))
-        }).raw_view_mut().into())
+        match_op!(self, a, map_raw!(a, r, {
+            r.slice_each_axis_inplace(|ax| slices.get(ax.axis.index()).cloned().unwrap_or_else(|| Slice::new(0, None, 1)));
+            r
+        }).into())
+    }
+
+    pub fn flatten(&self) -> NDArrayOwned {
+        match_op!(self, a, Array1::from_iter(view!(a).iter().cloned()).into_dyn().into())
+    }
+
+    pub fn tile(&self, reps: &[usize]) -> NDArrayOwned {
+        match_op!(self, a, tile(view!(a), reps).into())
+    }
+
+    // Broadcast dimensions get a stride of 0, so the result is always a read-only view
+    pub fn broadcast_to(&self, shape: &[usize]) -> Option<NDArrayView> {
+        match_op!(self, a, view!(a).broadcast(shape).map(|b| RawView::Const(b.raw_view()).into()))
+    }
+
+    pub fn slice_axis(&self, axis: usize, slice: Slice) -> NDArrayView {
+        match_op!(self, a, map_raw!(a, r, {
+            r.slice_axis_inplace(Axis(axis), slice);
+            r
+        }).into())
     }
 
     pub fn permute(&self, permutation: &[usize]) -> NDArrayView {
-        match self {
-            NDArrayView::Int(a) => view_mut!(a).permuted_axes(permutation).raw_view_mut().into(),
-            NDArrayView::Float(a) => view_mut!(a).permuted_axes(permutation).raw_view_mut().into(),
-            NDArrayView::Bool(a) => view_mut!(a).permuted_axes(permutation).raw_view_mut().into(),
-        }
+        match_op!(self, a, map_raw!(a, r, r.permuted_axes(permutation)).into())
+    }
+
+    pub fn remove_axes(&self, axes: &[usize]) -> NDArrayView {
+        match_op!(self, a, map_raw!(a, r, {
+            // Axes are removed from last to first so the remaining indices stay valid
+            for axis in axes.iter().rev() {
+                r = r.index_axis_move(Axis(*axis), 0);
+            }
+
+            r
+        }).into())
+    }
+
+    pub fn expand_dims(&self, axis: usize) -> NDArrayView {
+        match_op!(self, a, map_raw!(a, r, r.insert_axis(Axis(axis))).into())
+    }
+
+    pub fn swap_axes(&self, a: usize, b: usize) -> NDArrayView {
+        match_op!(self, v, map_raw!(v, r, {
+            r.swap_axes(a, b);
+            r
+        }).into())
     }
 
     pub fn matmul(&self, other: &NDArrayView) -> NDArrayOwned {
         match self {
             NDArrayView::Int(a) => {
-                let a_d2 = a.clone().into_dimensionality::<Ix2>().ok().unwrap();
+                let a_d2 = view!(a).clone().into_dimensionality::<Ix2>().unwrap();
 
                 match other {
                     NDArrayView::Int(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        view!(a_d2).dot(view!(b_d2)).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        a_d2.dot(&b_d2).into_dyn().into()
                     },
 
                     NDArrayView::Float(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let a_float = view!(a_d2).mapv(|i| i as f64);
-                        a_float.dot(view!(b_d2)).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let a_float = a_d2.mapv(|i| i as f64);
+                        a_float.dot(&b_d2).into_dyn().into()
                     },
 
                     NDArrayView::Bool(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let b_int = view!(b_d2).mapv(|i| i as i64);
-                        view!(a_d2).dot(&b_int).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let b_int = b_d2.mapv(|i| i as i64);
+                        a_d2.dot(&b_int).into_dyn().into()
                     },
                 }
             },
 
             NDArrayView::Float(a) => {
-                let a_d2 = a.clone().into_dimensionality::<Ix2>().ok().unwrap();
+                let a_d2 = view!(a).clone().into_dimensionality::<Ix2>().unwrap();
 
                 match other {
                     NDArrayView::Int(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let b_float = view!(b_d2).mapv(|i| i as f64);
-                        view!(a_d2).dot(&b_float).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let b_float = b_d2.mapv(|i| i as f64);
+                        a_d2.dot(&b_float).into_dyn().into()
                     },
 
                     NDArrayView::Float(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        view!(a_d2).dot(view!(b_d2)).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        a_d2.dot(&b_d2).into_dyn().into()
                     },
 
                     NDArrayView::Bool(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let b_float = view!(b_d2).mapv(|i| i as i64 as f64);
-                        view!(a_d2).dot(&b_float).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let b_float = b_d2.mapv(|i| i as i64 as f64);
+                        a_d2.dot(&b_float).into_dyn().into()
                     },
                 }
             },
             NDArrayView::Bool(a) => {
-                let a_d2 = a.clone().into_dimensionality::<Ix2>().ok().unwrap();
+                let a_d2 = view!(a).clone().into_dimensionality::<Ix2>().unwrap();
 
                 match other {
                     NDArrayView::Int(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let a_int = view!(a_d2).mapv(|i| i as i64);
-                        a_int.dot(view!(b_d2)).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let a_int = a_d2.mapv(|i| i as i64);
+                        a_int.dot(&b_d2).into_dyn().into()
                     },
 
                     NDArrayView::Float(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let a_float = view!(a_d2).mapv(|i| i as i64 as f64);
-                        a_float.dot(view!(b_d2)).into_dyn().into()
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let a_float = a_d2.mapv(|i| i as i64 as f64);
+                        a_float.dot(&b_d2).into_dyn().into()
                     },
 
                     NDArrayView::Bool(b) => {
-                        let b_d2 = b.clone().into_dimensionality::<Ix2>().ok().unwrap();
-                        let a_int = view!(a_d2).mapv(|i| i as i64);
-                        let b_int = view!(b_d2).mapv(|i| i as i64);
+                        let b_d2 = view!(b).clone().into_dimensionality::<Ix2>().unwrap();
+                        let a_int = a_d2.mapv(|i| i as i64);
+                        let b_int = b_d2.mapv(|i| i as i64);
                         a_int.dot(&b_int).into_dyn().into()
                     },
                 }
@@ -586,12 +911,123 @@ impl NDArrayView {
         }
     }
 
-    pub fn axis_reverse(&mut self, axis: usize) -> NDArrayView {
+    pub fn solve(&self, other: &NDArrayView) -> NDArrayOwned {
+        linalg::solve(&self.float_matrix().view(), &other.to_f64().view()).into()
+    }
+
+    pub fn inv(&self) -> NDArrayOwned {
+        linalg::inv(&self.float_matrix().view()).into_dyn().into()
+    }
+
+    pub fn det(&self) -> f64 {
+        linalg::det(&self.float_matrix().view())
+    }
+
+    pub fn slogdet(&self) -> NDArrayOwned {
+        let (sign, logdet) = linalg::slogdet(&self.float_matrix().view());
+
+        Array1::from(vec![sign, logdet]).into_dyn().into()
+    }
+
+    pub fn matrix_rank(&self, tol: Option<f64>) -> i64 {
+        linalg::matrix_rank(&self.float_matrix().view(), tol) as i64
+    }
+
+    pub fn matrix_power(&self, n: i64) -> NDArrayOwned {
+        linalg::matrix_power(&self.float_matrix().view(), n).into_dyn().into()
+    }
+
+    pub fn pinv(&self, rcond: Option<f64>) -> NDArrayOwned {
+        linalg::pinv(&self.float_matrix().view(), rcond).into_dyn().into()
+    }
+
+    pub fn lstsq(&self, other: &NDArrayView, rcond: Option<f64>) -> Vec<NDArrayOwned> {
+        let (x, residuals, rank, s) = linalg::lstsq(&self.float_matrix().view(), &other.to_f64().view(), rcond);
+
+        vec![x.into(), residuals.into_dyn().into(), ArrayD::from_elem(vec![], rank as i64).into(), s.into_dyn().into()]
+    }
+
+    pub fn norm(&self, p: f64) -> f64 {
+        linalg::vector_norm(self.to_f64().iter(), p)
+    }
+
+    pub fn axis_norm(&self, p: f64, axis: usize) -> NDArrayOwned {
+        self.to_f64().map_axis(Axis(axis), |lane| linalg::vector_norm(lane.iter(), p)).into()
+    }
+
+    pub fn matrix_norm(&self, norm: linalg::MatrixNorm) -> f64 {
+        linalg::matrix_norm(&self.float_matrix().view(), norm)
+    }
+
+    pub fn lu(&self) -> Vec<NDArrayOwned> {
+        let (p, l, u) = linalg::lu(&self.float_matrix().view());
+
+        vec![p.into_dyn().into(), l.into_dyn().into(), u.into_dyn().into()]
+    }
+
+    pub fn qr(&self, complete: bool) -> Vec<NDArrayOwned> {
+        let (q, r) = linalg::qr(&self.float_matrix().view(), complete);
+
+        vec![q.into_dyn().into(), r.into_dyn().into()]
+    }
+
+    pub fn cholesky(&self) -> NDArrayOwned {
+        linalg::cholesky(&self.float_matrix().view()).into_dyn().into()
+    }
+
+    pub fn svd(&self, full: bool) -> Vec<NDArrayOwned> {
+        let (u, s, vt) = linalg::svd(&self.float_matrix().view(), full);
+
+        vec![u.into_dyn().into(), s.into_dyn().into(), vt.into_dyn().into()]
+    }
+
+    pub fn eigh(&self) -> Vec<NDArrayOwned> {
+        let (w, v) = linalg::eigh(&self.float_matrix().view());
+
+        vec![w.into_dyn().into(), v.into_dyn().into()]
+    }
+
+    pub fn eig(&self) -> Vec<NDArrayOwned> {
+        let (w_re, w_im, v_re, v_im) = linalg::eig(&self.float_matrix().view());
+
+        vec![w_re.into_dyn().into(), w_im.into_dyn().into(), v_re.into_dyn().into(), v_im.into_dyn().into()]
+    }
+
+    pub fn diag(&self, k: i64) -> NDArrayOwned {
+        if self.shape().len() == 1 {
+            match self {
+                NDArrayView::Int(a) => diag_from(&view!(a).view().into_dimensionality::<Ix1>().unwrap(), k).into_dyn().into(),
+                NDArrayView::Float(a) => diag_from(&view!(a).view().into_dimensionality::<Ix1>().unwrap(), k).into_dyn().into(),
+                NDArrayView::Bool(a) => diag_from(&view!(a).view().into_dimensionality::<Ix1>().unwrap(), k).into_dyn().into(),
+            }
+
+        } else {
+            match_op!(self, a, diagonal_axis(view!(a).view().into_dimensionality::<Ix2>().unwrap(), k).into_dyn().to_owned().into())
+        }
+    }
+
+    pub fn diagonal(&mut self, k: i64) -> NDArrayView {
+        match_op!(self, a, map_raw!(a, r, diagonal_axis(r.into_dimensionality::<Ix2>().unwrap(), k).into_dyn()).into())
+    }
+
+    pub fn triu(&self, k: i64) -> NDArrayOwned {
+        match_op!(self, a, triangle(view!(a), k, true).into())
+    }
+
+    pub fn tril(&self, k: i64) -> NDArrayOwned {
+        match_op!(self, a, triangle(view!(a), k, false).into())
+    }
+
+    pub fn trace(&self, k: i64) -> NDArrayOwned {
         match self {
-            NDArrayView::Int(a) => reverse_axis(&mut view_mut!(a), Axis(axis)).raw_view_mut().into(),
-            NDArrayView::Float(a) => reverse_axis(&mut view_mut!(a), Axis(axis)).raw_view_mut().into(),
-            NDArrayView::Bool(a) => reverse_axis(&mut view_mut!(a), Axis(axis)).raw_view_mut().into()
-        }    
+            NDArrayView::Int(a) => ArrayD::from_elem(vec![], diagonal_axis(view!(a).view().into_dimensionality::<Ix2>().unwrap(), k).sum()).into(),
+            NDArrayView::Float(a) => ArrayD::from_elem(vec![], diagonal_axis(view!(a).view().into_dimensionality::<Ix2>().unwrap(), k).sum()).into(),
+            NDArrayView::Bool(a) => ArrayD::from_elem(vec![], diagonal_axis(view!(a).view().into_dimensionality::<Ix2>().unwrap(), k).iter().filter(|i| **i).count() as i64).into(),
+        }
+    }
+
+    pub fn axis_reverse(&mut self, axis: usize) -> NDArrayView {
+        match_op!(self, a, map_raw!(a, r, reverse_axis(r, Axis(axis))).into())
     }
 
     pub fn axis_sum(&self, axis: usize) -> NDArrayOwned {
@@ -610,6 +1046,14 @@ impl NDArrayView {
         }
     }
 
+    pub fn shuffle<R: Rng + ?Sized>(&self, rng: &mut R) {
+        match_op!(self, a, shuffle_view(view_mut!(a), rng))
+    }
+
+    pub fn select(&self, axis: usize, indices: &[usize]) -> NDArrayOwned {
+        match_op!(self, a, view!(a).select(Axis(axis), indices).into())
+    }
+
     pub fn axis_argsort(&self, axis: usize) -> NDArrayOwned {
         match self {
             NDArrayView::Int(a) => argsort_axis(view!(a), Axis(axis)).into(),
@@ -756,6 +1200,32 @@ impl NDArrayView {
         }
     }
 
+    // Integers wrap on overflow like in NumPy and booleans are left unchanged
+    pub fn abs(&self) -> NDArrayOwned {
+        match self {
+            NDArrayView::Int(a) => view!(a).mapv(|i| i.wrapping_abs()).into(),
+            NDArrayView::Float(a) => view!(a).abs().into(),
+            NDArrayView::Bool(a) => view!(a).clone().into_owned().into(),
+        }
+    }
+
+    // f64::signum returns 1 for zero, while NumPy returns 0
+    pub fn sign(&self) -> NDArrayOwned {
+        match self {
+            NDArrayView::Int(a) => view!(a).mapv(|i| i.signum()).into(),
+            NDArrayView::Float(a) => view!(a).mapv(|i| if i == 0.0 { 0.0 } else { i.signum() }).into(),
+            NDArrayView::Bool(a) => view!(a).clone().into_owned().into(),
+        }
+    }
+
+    pub fn square(&self) -> NDArrayOwned {
+        match self {
+            NDArrayView::Int(a) => view!(a).mapv(|i| i.wrapping_mul(i)).into(),
+            NDArrayView::Float(a) => view!(a).mapv(|i| i * i).into(),
+            NDArrayView::Bool(a) => view!(a).clone().into_owned().into(),
+        }
+    }
+
     unary_float_fn!(cos);
     unary_float_fn!(sin);
     unary_float_fn!(tan);
@@ -770,6 +1240,11 @@ impl NDArrayView {
     unary_mapv_float_fn!(cosh);
     unary_mapv_float_fn!(sinh);
     unary_mapv_float_fn!(tanh);
+    unary_mapv_float_fn!(exp2);
+    unary_mapv_float_fn!(expm1, exp_m1);
+    unary_mapv_float_fn!(log1p, ln_1p);
+    unary_mapv_float_fn!(cbrt);
+    unary_mapv_float_fn!(reciprocal, recip);
 }
 
 impl std::fmt::Display for NDArrayView {